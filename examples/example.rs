@@ -1,10 +1,9 @@
 #[derive(PartialEq, Eq, Clone, Copy, Hash)]
 enum Actions {
     Debug,
-    Left,
-    Right,
+    Move,
+    Look,
     Click,
-    MouseXP, MouseXN, MouseYP, MouseYN,
     MouseScrollP, MouseScrollN
 }
 use winit_input_map::*;
@@ -14,24 +13,21 @@ use winit::{event::*, keyboard::KeyCode, application::*, window::*, event_loop::
 fn main() {
     let mut input = input_map!(
         (Debug, KeyCode::Space, GamepadButton::South),
-        (Left,
-            KeyCode::ArrowLeft,  KeyCode::KeyA,
-            GamepadInput::Axis(Axis::LeftStickX, AxisSign::Neg)
-        ),
-        (Right,
-            KeyCode::ArrowRight, KeyCode::KeyD,
-            GamepadInput::Axis(Axis::LeftStickX, AxisSign::Pos)
-        ),
         (Click, MouseButton::Left),
-        (MouseXP, InputCode::MOUSE_MOVE_X_POS),
-        (MouseXN, InputCode::MOUSE_MOVE_X_NEG),
-        (MouseYP, InputCode::MOUSE_MOVE_Y_POS),
-        (MouseYN, InputCode::MOUSE_MOVE_Y_NEG),
         (MouseScrollP, InputCode::MOUSE_SCROLL_POS),
         (MouseScrollN, InputCode::MOUSE_SCROLL_NEG)
     );
-    input.mouse_scale = 1.0;
-    input.scroll_scale = 1.0;
+    // one 2D action for moving instead of an action for each direction
+    input.set_axis_2d(Move, Axis2d::wasd().and(Axis2d::arrows()).and(Axis2d::left_stick()));
+    input.set_axis_2d(Look, Axis2d {
+        normalize: false,
+        ..Axis2d::new(
+            &[InputCode::MOUSE_MOVE_X_POS], &[InputCode::MOUSE_MOVE_X_NEG],
+            &[InputCode::MOUSE_MOVE_Y_POS], &[InputCode::MOUSE_MOVE_Y_NEG]
+        )
+    });
+    input.set_mouse_scale(1.0);
+    input.set_scroll_scale(1.0);
     
     let gilrs = Gilrs::new().unwrap();
     let event_loop = EventLoop::new().unwrap();
@@ -41,7 +37,7 @@ struct App { window: Option<Window>, input: InputMap<Actions>, gilrs: Gilrs }
 impl ApplicationHandler for App {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         let window_settings = Window::default_attributes();
-        let window = event_loop.create_window();
+        let window = event_loop.create_window(window_settings);
         self.window = Some(window.unwrap());
     }
     fn window_event(
@@ -65,12 +61,12 @@ impl ApplicationHandler for App {
                 if s.contains(&Debug) { Some(*a) } else { None }
             }).collect::<Vec<InputCode>>())
         }
-        if input.pressing(Right) || input.pressing(Left) {
-            println!("axis: {}", input.axis(Right, Left))
+        if input.pressing(Move) {
+            println!("moving: {:?}", input.axis_2d(Move))
         }
 
-        let mouse_move = input.dir(MouseXP, MouseXN, MouseYP, MouseYN);
-        if mouse_move != (0.0, 0.0) {
+        let mouse_move = input.axis_2d(Look);
+        if mouse_move != Default::default() {
             println!(
                 "mouse moved: {:?} and is now at {:?}",
                 mouse_move, input.mouse_pos
@@ -82,7 +78,7 @@ impl ApplicationHandler for App {
         if scroll != 0.0 {
             println!("scrolling {}", scroll);
         }
-        if let Some(other) = input.other_pressed {
+        if let Some(other) = input.recently_pressed {
             println!("{other:?}");
         }
         std::thread::sleep(std::time::Duration::from_millis(100));
@@ -90,4 +86,3 @@ impl ApplicationHandler for App {
         input.init();
     }
 }
-
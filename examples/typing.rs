@@ -16,7 +16,9 @@ fn main() {
 struct App { window: Option<Window>, input: InputMap<Action>, gilrs: Gilrs, text: String }
 impl ApplicationHandler for App {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
-        self.window = Some(event_loop.create_window(Window::default_attributes()).unwrap());
+        let window = event_loop.create_window(Window::default_attributes()).unwrap();
+        self.input.set_text_input_mode(&window, true);
+        self.window = Some(window);
     }
     fn window_event(&mut self, event_loop: &ActiveEventLoop, _: WindowId, event: WindowEvent) {
         self.input.update_with_window_event(&event);
@@ -31,8 +33,14 @@ impl ApplicationHandler for App {
         if self.input.pressed(Action::Return) {
             println!("{}", self.text);
             self.text = String::new();
-        } else if let Some(new) = &self.input.text_typed {
-            self.text.push_str(new);
+        } else {
+            for event in self.input.drain_text() {
+                match event {
+                    TextEvent::Text(new) => self.text.push_str(&new),
+                    TextEvent::Key(KeyCode::Backspace) => { self.text.pop(); },
+                    _ => ()
+                }
+            }
         }
 
         self.input.init();
@@ -0,0 +1,32 @@
+//! Runs in the browser as well as natively. Build for the web with
+//! `cargo build --example web --target wasm32-unknown-unknown` and serve it with a tool such as
+//! wasm-bindgen or trunk. Gamepads show up once a button is pressed, as browsers hide them until
+//! then.
+use winit_input_map::*;
+use winit::{keyboard::KeyCode, window::Window};
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum Action { Jump, Left, Right }
+
+fn main() {
+    use Action::*;
+    let input = input_map!(
+        (Jump,  KeyCode::Space, GamepadButton::South),
+        (Left,  KeyCode::KeyA,  GamepadInput::Axis(GamepadAxis::LeftStickX, AxisSign::Neg)),
+        (Right, KeyCode::KeyD,  GamepadInput::Axis(GamepadAxis::LeftStickX, AxisSign::Pos))
+    );
+    #[allow(unused_mut)]
+    let mut attributes = Window::default_attributes();
+    #[cfg(target_arch = "wasm32")]
+    {
+        // adds the canvas to the page
+        use winit::platform::web::WindowAttributesExtWebSys;
+        attributes = attributes.with_append(true);
+    }
+    let mut jumps = 0;
+    InputApp::new(input, attributes, move |input, window, _| {
+        if input.pressed(Jump) { jumps += 1 }
+        // stdout goes nowhere in the browser, the title shows in the tab instead
+        window.set_title(&format!("jumps: {jumps} x: {:.2}", input.axis(Right, Left)));
+    }).run().unwrap();
+}
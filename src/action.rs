@@ -0,0 +1,90 @@
+use std::{borrow::Borrow, collections::HashMap, hash::Hash};
+/// An action type that can be stored in a flat array instead of a hash map, so checking an
+/// action doesnt need to hash it. Usually implemented with the `actions!` macro, see
+/// `InputMap::with_dense_storage`
+pub trait IndexedAction: Hash + Eq + Clone {
+    /// How many actions there are
+    const COUNT: usize;
+    /// A different number below `COUNT` for each action
+    fn index(&self) -> usize;
+}
+/// the value of an action and if it was pressed or released this loop
+pub(crate) type ActionVal = (f32, bool, bool);
+/// everything kept about an action, so checking if its pressed only needs one lookup
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct ActionSlot {
+    pub(crate) val: ActionVal,
+    /// the press and release thresholds, if it doesnt use `press_sensitivity`
+    pub(crate) threshold: Option<(f32, f32)>,
+    /// if it has thresholds and is pressed
+    pub(crate) past_threshold: bool,
+    /// its value while its being held down but losing its rollover
+    pub(crate) overridden: Option<f32>,
+    /// if its ignored until its input is let go, see `InputMap::consume`
+    pub(crate) consumed: bool
+}
+/// where the values of actions are kept, see `IndexedAction`
+#[derive(Debug)]
+pub(crate) enum ActionVals<F> {
+    Map(HashMap<F, ActionSlot>),
+    Dense(fn(&F) -> usize, Vec<Option<(F, ActionSlot)>>)
+}
+impl<F: Hash + Eq + Clone> ActionVals<F> {
+    /// moves the values into a flat array
+    pub(crate) fn dense(&self) -> Self where F: IndexedAction {
+        let mut vals = vec![None; F::COUNT];
+        for (action, &slot) in self.slots() { vals[action.index()] = Some((action.clone(), slot)) }
+        Self::Dense(F::index, vals)
+    }
+    pub(crate) fn get(&self, action: &F) -> Option<&ActionVal> {
+        self.slot(action).map(|slot| &slot.val)
+    }
+    pub(crate) fn slot(&self, action: &F) -> Option<&ActionSlot> {
+        match self {
+            Self::Map(map) => map.get(action),
+            Self::Dense(index, vals) => vals.get(index(action))?.as_ref().map(|(_, slot)| slot)
+        }
+    }
+    /// the slot of an action by a borrowed form of it. Dense storage has to search for it as the
+    /// index needs the action itself
+    pub(crate) fn slot_by<Q: ?Sized + Hash + Eq>(&self, action: &Q) -> Option<&ActionSlot>
+    where F: Borrow<Q> {
+        match self {
+            Self::Map(map) => map.get(action),
+            Self::Dense(..) => self.slots().find(|(a, _)| (*a).borrow() == action).map(|(_, s)| s)
+        }
+    }
+    /// the slot of an action, made empty if it doesnt have one yet
+    pub(crate) fn slot_mut(&mut self, action: &F) -> &mut ActionSlot {
+        match self {
+            Self::Map(map) => {
+                if !map.contains_key(action) { map.insert(action.clone(), ActionSlot::default()); }
+                map.get_mut(action).unwrap()
+            },
+            Self::Dense(index, vals) => &mut vals[index(action)]
+                .get_or_insert_with(|| (action.clone(), ActionSlot::default())).1
+        }
+    }
+    pub(crate) fn slots(&self) -> impl Iterator<Item = (&F, &ActionSlot)> {
+        let (map, dense) = match self {
+            Self::Map(map) => (Some(map), None),
+            Self::Dense(_, vals) => (None, Some(vals))
+        };
+        map.into_iter().flatten()
+            .chain(dense.into_iter().flatten().flatten().map(|(action, slot)| (action, slot)))
+    }
+    pub(crate) fn slots_mut(&mut self) -> impl Iterator<Item = (&F, &mut ActionSlot)> {
+        let (map, dense) = match self {
+            Self::Map(map) => (Some(map), None),
+            Self::Dense(_, vals) => (None, Some(vals))
+        };
+        map.into_iter().flatten()
+            .chain(dense.into_iter().flatten().flatten().map(|(action, slot)| (&*action, slot)))
+    }
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&F, &ActionVal)> {
+        self.slots().map(|(action, slot)| (action, &slot.val))
+    }
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = (&F, &mut ActionVal)> {
+        self.slots_mut().map(|(action, slot)| (action, &mut slot.val))
+    }
+}
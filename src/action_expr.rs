@@ -0,0 +1,50 @@
+/// An expression over other actions that drives a composite action, see
+/// `InputMap::add_composite`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActionExpr<F> {
+    /// The value of an action
+    Action(F),
+    Value(f32),
+    /// The lowest value, so its only pressed while all of them are. Works as `&&`
+    All(Vec<ActionExpr<F>>),
+    /// The highest value, so its pressed while any of them are. Works as `||`
+    Any(Vec<ActionExpr<F>>),
+    /// 1 minus the value, so its pressed while the expression isnt
+    Not(Box<ActionExpr<F>>),
+    Add(Box<ActionExpr<F>>, Box<ActionExpr<F>>),
+    /// Useful for turning two actions into an axis
+    Sub(Box<ActionExpr<F>>, Box<ActionExpr<F>>)
+}
+impl<F: Clone + PartialEq> ActionExpr<F> {
+    pub fn all(actions: &[F]) -> Self {
+        Self::All(actions.iter().map(|action| Self::Action(action.clone())).collect())
+    }
+    pub fn any(actions: &[F]) -> Self {
+        Self::Any(actions.iter().map(|action| Self::Action(action.clone())).collect())
+    }
+    pub fn axis(pos: F, neg: F) -> Self {
+        Self::Sub(Box::new(Self::Action(pos)), Box::new(Self::Action(neg)))
+    }
+    /// Works out the value of the expression using `val` for the value of each action
+    pub fn eval(&self, val: &impl Fn(&F) -> f32) -> f32 {
+        match self {
+            Self::Action(action) => val(action),
+            Self::Value(value) => *value,
+            Self::All(exprs) => exprs.iter().map(|e| e.eval(val)).reduce(f32::min).unwrap_or(0.0),
+            Self::Any(exprs) => exprs.iter().map(|e| e.eval(val)).fold(0.0, f32::max),
+            Self::Not(expr) => 1.0 - expr.eval(val).clamp(0.0, 1.0),
+            Self::Add(a, b) => a.eval(val) + b.eval(val),
+            Self::Sub(a, b) => a.eval(val) - b.eval(val)
+        }
+    }
+    /// Checks if the expression depends on `action`
+    pub fn uses(&self, action: &F) -> bool {
+        match self {
+            Self::Action(a) => a == action,
+            Self::Value(_) => false,
+            Self::All(exprs) | Self::Any(exprs) => exprs.iter().any(|e| e.uses(action)),
+            Self::Not(expr) => expr.uses(action),
+            Self::Add(a, b) | Self::Sub(a, b) => a.uses(action) || b.uses(action)
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::InputMap;
+use winit::{
+    application::ApplicationHandler,
+    event::{DeviceEvent, DeviceId, WindowEvent},
+    event_loop::{ActiveEventLoop, EventLoop},
+    error::EventLoopError,
+    window::{Window, WindowAttributes, WindowId},
+};
+use std::{cmp::Eq, hash::Hash};
+/// An `ApplicationHandler` that owns an input map and a window, forwarding events and calling
+/// `init` for you so only the code that runs each loop needs to be written. Gamepads are handled
+/// as well with the `gamepad` feature. Closing the window exits.
+/// ```no_run
+/// # use winit_input_map::*;
+/// # use winit::{window::Window, keyboard::KeyCode};
+/// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+/// # enum Action { Jump }
+/// let input = input_map!((Action::Jump, KeyCode::Space));
+/// InputApp::new(input, Window::default_attributes(), |input, _window, _event_loop| {
+///     if input.pressed(Action::Jump) { println!("jump") }
+/// }).run().unwrap();
+/// ```
+pub struct InputApp<F, FN>
+where F: Hash + Eq + Clone, FN: FnMut(&mut InputMap<F>, &Window, &ActiveEventLoop) {
+    pub input: InputMap<F>,
+    /// Created once the app is resumed
+    pub window: Option<Window>,
+    /// Used to create the window
+    pub attributes: WindowAttributes,
+    update: FN
+}
+impl<F, FN> InputApp<F, FN>
+where F: Hash + Eq + Clone, FN: FnMut(&mut InputMap<F>, &Window, &ActiveEventLoop) {
+    /// `update` is called every loop after events have been handled
+    pub fn new(input: InputMap<F>, attributes: WindowAttributes, update: FN) -> Self {
+        #[cfg(feature = "gamepad")]
+        let input = if input.gilrs.is_none() { input.with_gamepads() } else { input };
+        Self { input, window: None, attributes, update }
+    }
+    /// Creates an event loop and runs the app on it
+    pub fn run(mut self) -> Result<(), EventLoopError> {
+        EventLoop::new()?.run_app(&mut self)
+    }
+}
+impl<F, FN> ApplicationHandler for InputApp<F, FN>
+where F: Hash + Eq + Clone, FN: FnMut(&mut InputMap<F>, &Window, &ActiveEventLoop) {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        if self.window.is_some() { return }
+        match event_loop.create_window(self.attributes.clone()) {
+            Ok(window) => {
+                self.input.window_size = window.inner_size();
+                self.input.scale_factor = window.scale_factor();
+                self.window = Some(window);
+            },
+            Err(_) => event_loop.exit()
+        }
+    }
+    fn window_event(&mut self, event_loop: &ActiveEventLoop, _: WindowId, event: WindowEvent) {
+        self.input.update_with_window_event(&event);
+        if let WindowEvent::CloseRequested = &event { event_loop.exit() }
+    }
+    fn device_event(&mut self, _: &ActiveEventLoop, device_id: DeviceId, event: DeviceEvent) {
+        self.input.update_with_device_event_from(device_id, &event);
+    }
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        let Some(window) = &self.window else { return };
+        #[cfg(feature = "gamepad")]
+        self.input.update_gamepads();
+        self.input.dispatch();
+        (self.update)(&mut self.input, window, event_loop);
+        self.input.init();
+    }
+}
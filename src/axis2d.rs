@@ -0,0 +1,99 @@
+use crate::{AxisSign, DeviceInput, InputCode, JoystickId};
+use winit::keyboard::KeyCode;
+use std::f32::consts::FRAC_PI_4;
+/// Four sets of inputs turned straight into a 2D direction with positive being right and up, see
+/// `InputMap::set_axis_2d`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Axis2d {
+    pub right: Vec<InputCode>,
+    pub left: Vec<InputCode>,
+    pub up: Vec<InputCode>,
+    pub down: Vec<InputCode>,
+    /// Keeps the length at 1 or less so diagonals arent faster, the same as `dir_max_len_1`.
+    /// On by default
+    pub normalize: bool,
+    /// Snaps the direction to the nearest of 8 directions while keeping its length
+    pub snap_8: bool
+}
+impl Axis2d {
+    pub fn new(right: &[InputCode], left: &[InputCode], up: &[InputCode], down: &[InputCode]) -> Self {
+        Self {
+            right: right.to_vec(), left: left.to_vec(), up: up.to_vec(), down: down.to_vec(),
+            normalize: true, snap_8: false
+        }
+    }
+    pub fn wasd() -> Self {
+        Self::new(&[KeyCode::KeyD.into()], &[KeyCode::KeyA.into()], &[KeyCode::KeyW.into()], &[KeyCode::KeyS.into()])
+    }
+    pub fn arrows() -> Self {
+        Self::new(
+            &[KeyCode::ArrowRight.into()], &[KeyCode::ArrowLeft.into()],
+            &[KeyCode::ArrowUp.into()], &[KeyCode::ArrowDown.into()]
+        )
+    }
+    #[cfg(feature = "gamepad")]
+    pub fn left_stick() -> Self {
+        use gilrs::Axis;
+        Self::new(
+            &[InputCode::gamepad_axis_pos(Axis::LeftStickX)], &[InputCode::gamepad_axis_neg(Axis::LeftStickX)],
+            &[InputCode::gamepad_axis_pos(Axis::LeftStickY)], &[InputCode::gamepad_axis_neg(Axis::LeftStickY)]
+        )
+    }
+    #[cfg(feature = "gamepad")]
+    pub fn dpad() -> Self {
+        use gilrs::Button;
+        Self::new(
+            &[Button::DPadRight.into()], &[Button::DPadLeft.into()],
+            &[Button::DPadUp.into()], &[Button::DPadDown.into()]
+        )
+    }
+    /// The inputs of a virtual joystick, see `InputMap::add_virtual_joystick`
+    pub fn virtual_joystick(id: JoystickId) -> Self {
+        let x = |sign| InputCode::from(DeviceInput::JoystickX(id, sign));
+        let y = |sign| InputCode::from(DeviceInput::JoystickY(id, sign));
+        Self::new(
+            &[x(AxisSign::Pos)], &[x(AxisSign::Neg)], &[y(AxisSign::Pos)], &[y(AxisSign::Neg)]
+        )
+    }
+    /// Adds the inputs of `other`, such as a stick to WASD
+    /// ```
+    /// # use winit_input_map::*;
+    /// let movement = Axis2d::wasd().and(Axis2d::arrows()).and(Axis2d::left_stick());
+    /// ```
+    pub fn and(mut self, other: Self) -> Self {
+        self.right.extend(other.right);
+        self.left.extend(other.left);
+        self.up.extend(other.up);
+        self.down.extend(other.down);
+        self
+    }
+    /// Checks if `code` is one of the inputs
+    pub fn contains(&self, code: InputCode) -> bool {
+        [&self.right, &self.left, &self.up, &self.down].iter().any(|codes| codes.contains(&code))
+    }
+    /// Works out the direction using `val` for the value of each input
+    pub fn eval(&self, val: impl Fn(InputCode) -> f32) -> (f32, f32) {
+        self.eval_swizzled(val, |_| false)
+    }
+    /// like `eval` but inputs that are `swizzled` drive the other axis, see `Processor::Swizzle`
+    pub(crate) fn eval_swizzled(
+        &self, val: impl Fn(InputCode) -> f32, swizzled: impl Fn(InputCode) -> bool
+    ) -> (f32, f32) {
+        let max = |codes: &[InputCode], swizzle: bool| codes.iter()
+            .filter(|&&code| swizzled(code) == swizzle)
+            .map(|&code| val(code)).fold(0.0, f32::max);
+        let side = |own, other| max(own, false).max(max(other, true));
+        let mut x = side(&self.right, &self.up) - side(&self.left, &self.down);
+        let mut y = side(&self.up, &self.right) - side(&self.down, &self.left);
+        if self.normalize {
+            let len = (x * x + y * y).sqrt().max(1.0);
+            (x, y) = (x / len, y / len);
+        }
+        if self.snap_8 && (x != 0.0 || y != 0.0) {
+            let len = (x * x + y * y).sqrt();
+            let angle = (y.atan2(x) / FRAC_PI_4).round() * FRAC_PI_4;
+            (x, y) = (angle.cos() * len, angle.sin() * len);
+        }
+        (x, y)
+    }
+}
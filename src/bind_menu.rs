@@ -0,0 +1,97 @@
+use crate::{InputMap, InputCode};
+use std::{cmp::Eq, hash::Hash};
+/// Builds the rows of a controls menu from a list of actions and their names so a UI can render
+/// them directly.
+/// ```
+/// # use winit_input_map::*;
+/// # use winit::keyboard::KeyCode;
+/// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+/// # enum Action { Jump, Interact }
+/// # use Action::*;
+/// let mut input = input_map!(
+///     (Jump,     KeyCode::Space),
+///     (Interact, KeyCode::Space, KeyCode::KeyE)
+/// );
+/// let menu = BindMenuModel::new(&[(Jump, "Jump"), (Interact, "Interact")]);
+/// let mut rebinding = None;
+/// for row in menu.rows(&input) {
+///     for slot in &row.binds {
+///         let conflict = if slot.conflicts.is_empty() { "" } else { "!" };
+///         println!("{}: {:?}{conflict}", row.name, slot.code);
+///         // when the player clicks on a bind
+///         rebinding = Some(slot.rebind);
+///     }
+/// }
+/// // later, once the player presses something
+/// if let (Some(handle), Some(code)) = (rebinding, input.recently_pressed) {
+///     input.rebind(handle, code);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindMenuModel<F: Hash + Eq + Clone> {
+    /// The actions shown in the menu in order, along with their names
+    pub actions: Vec<(F, String)>
+}
+/// A row of a controls menu, see `BindMenuModel`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindRow<F: Hash + Eq + Clone> {
+    /// The action this row is for
+    pub action: F,
+    /// The name given to the action in `BindMenuModel::new`
+    pub name: String,
+    /// What the action is currently bound to
+    pub binds: Vec<BindSlot<F>>,
+    /// Used to add a new bind to the action rather than replacing one
+    pub add: RebindHandle<F>
+}
+/// A single bind in a `BindRow`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindSlot<F: Hash + Eq + Clone> {
+    /// The input code the action is bound to
+    pub code: InputCode,
+    /// Other actions in the menu that are bound to the same input code
+    pub conflicts: Vec<F>,
+    /// Used to replace this bind, see `InputMap::rebind`
+    pub rebind: RebindHandle<F>
+}
+/// Refers to a bind to replace, or a new bind to add when `old` is `None`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct RebindHandle<F: Hash + Eq + Clone> {
+    /// The action whose bind is changed
+    pub action: F,
+    /// The input code thats unbound when the new one is bound, if any
+    pub old: Option<InputCode>
+}
+impl<F: Hash + Eq + Clone> BindMenuModel<F> {
+    /// Makes a menu showing `actions` in order, each with the name its shown with
+    pub fn new(actions: &[(F, &str)]) -> Self {
+        Self { actions: actions.iter().map(|(a, name)| (a.clone(), name.to_string())).collect() }
+    }
+    /// Returns a row for each action using the current binds of `input`
+    pub fn rows(&self, input: &InputMap<F>) -> Vec<BindRow<F>> {
+        self.actions.iter().map(|(action, name)| {
+            let mut binds: Vec<BindSlot<F>> = input.binds.iter()
+                .filter(|(_, actions)| actions.contains(action))
+                .map(|(&code, actions)| BindSlot {
+                    code,
+                    conflicts: self.actions.iter()
+                        .map(|(a, _)| a.clone())
+                        .filter(|a| a != action && actions.contains(a))
+                        .collect(),
+                    rebind: RebindHandle { action: action.clone(), old: Some(code) }
+                }).collect();
+            // the binds are in a hash map so sort them to stop the menu from shuffling around
+            binds.sort_by_cached_key(|slot| format!("{:?}", slot.code));
+            let add = RebindHandle { action: action.clone(), old: None };
+            BindRow { action: action.clone(), name: name.clone(), binds, add }
+        }).collect()
+    }
+}
+impl<F: Hash + Eq + Clone> InputMap<F> {
+    /// Replaces the bind `handle` refers to with `new`, or adds `new` as a bind if its a handle
+    /// for adding binds. Usually used with `recently_pressed`
+    pub fn rebind(&mut self, handle: RebindHandle<F>, new: InputCode) {
+        if let Some(old) = handle.old { self.unbind(old, handle.action.clone()) }
+        self.bind(new, handle.action)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::InputCode;
+use std::collections::{hash_map, HashMap};
+use std::ops::Index;
+use std::sync::atomic::{AtomicU64, Ordering};
+/// the hasher of maps keyed by input codes, which are checked on every event. Fx hashing with the
+/// `fast-binds` feature
+#[cfg(feature = "fast-binds")]
+pub(crate) type BindHasher = rustc_hash::FxBuildHasher;
+#[cfg(not(feature = "fast-binds"))]
+pub(crate) type BindHasher = std::hash::RandomState;
+/// Input codes and the actions bound to them, see `InputMap::binds`. The `fast-binds` feature
+/// makes looking up binds faster without changing how theyre used
+#[derive(Debug, Clone)]
+pub struct Binds<F> {
+    map: HashMap<InputCode, Vec<F>, BindHasher>,
+    /// changes every time the binds do, so caches of them know when to be remade
+    generation: u64
+}
+/// the next generation of any binds, so two binds only share one if one is a copy of the other
+fn next_generation() -> u64 {
+    static GENERATION: AtomicU64 = AtomicU64::new(0);
+    GENERATION.fetch_add(1, Ordering::Relaxed)
+}
+impl<F> Default for Binds<F> {
+    fn default() -> Self {
+        Self { map: HashMap::default(), generation: next_generation() }
+    }
+}
+impl<F> Binds<F> {
+    /// The actions bound to `code`
+    pub fn get(&self, code: &InputCode) -> Option<&[F]> {
+        self.map.get(code).map(Vec::as_slice)
+    }
+    /// If anything is bound to `code`
+    pub fn contains_key(&self, code: &InputCode) -> bool {
+        self.map.contains_key(code)
+    }
+    /// Every input code and the actions bound to it, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&InputCode, &[F])> {
+        self.map.iter().map(|(code, actions)| (code, actions.as_slice()))
+    }
+    /// Every input code with something bound to it, in no particular order
+    pub fn keys(&self) -> impl Iterator<Item = &InputCode> {
+        self.map.keys()
+    }
+    /// How many input codes have something bound to them
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+    pub(crate) fn generation(&self) -> u64 {
+        self.generation
+    }
+    /// the map to change the binds through
+    pub(crate) fn map_mut(&mut self) -> &mut HashMap<InputCode, Vec<F>, BindHasher> {
+        self.generation = next_generation();
+        &mut self.map
+    }
+    /// the map for changes that are undone before anything else sees the binds, which dont need
+    /// caches remade
+    pub(crate) fn map_mut_untracked(&mut self) -> &mut HashMap<InputCode, Vec<F>, BindHasher> {
+        &mut self.map
+    }
+}
+impl<F> Index<&InputCode> for Binds<F> {
+    type Output = [F];
+    /// Panics if nothing is bound to `code`
+    fn index(&self, code: &InputCode) -> &[F] {
+        &self.map[code]
+    }
+}
+impl<F> IntoIterator for Binds<F> {
+    type Item = (InputCode, Vec<F>);
+    type IntoIter = hash_map::IntoIter<InputCode, Vec<F>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter()
+    }
+}
@@ -0,0 +1,32 @@
+use std::f32::consts::{PI, TAU};
+/// Turns a stick into flick stick controls, see `InputMap::set_flick_stick`. Pushing the stick
+/// all the way turns the camera to face the direction it was pushed in and rotating the stick
+/// afterwards turns the camera with it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlickStick<F> {
+    pub right: F,
+    pub left: F,
+    pub up: F,
+    pub down: F,
+    /// How far the stick needs to be pushed to flick, defaults to 0.9
+    pub threshold: f32
+}
+impl<F> FlickStick<F> {
+    pub fn new(right: F, left: F, up: F, down: F) -> Self {
+        Self { right, left, up, down, threshold: 0.9 }
+    }
+    /// Returns how many degrees to turn for a stick position, updating the last angle of the stick
+    pub(crate) fn process(&self, x: f32, y: f32, last: &mut Option<f32>) -> f32 {
+        if (x * x + y * y).sqrt() < self.threshold {
+            *last = None;
+            return 0.0
+        }
+        // 0 when pushed forward, going clockwise
+        let angle = x.atan2(y);
+        let turn = match last.replace(angle) {
+            Some(last) => (angle - last + PI).rem_euclid(TAU) - PI,
+            None => angle
+        };
+        turn.to_degrees()
+    }
+}
@@ -0,0 +1,60 @@
+/// Turns gyro readings into camera movement, see `InputMap::set_gyro_aim`. The defaults are a
+/// reasonable starting point for a first person camera.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GyroAim<F> {
+    /// How many degrees the camera turns for each degree the gamepad turns when turned slowly
+    pub min_sensitivity: f32,
+    /// How many degrees the camera turns for each degree the gamepad turns when turned quickly
+    pub max_sensitivity: f32,
+    /// Speeds in degrees per second between which sensitivity goes from min to max. Setting them
+    /// the same turns off acceleration
+    pub min_speed: f32,
+    pub max_speed: f32,
+    /// Speeds in degrees per second below this are reduced to hide the shaking of the players
+    /// hands, making small adjustments easier
+    pub tightening: f32,
+    /// Uses gravity from the accelerometer to turn based on how the gamepad turns relative to
+    /// the ground rather than itself, so it works however the gamepad is held
+    pub gravity_correction: bool,
+    /// Only aims with the gyro while this action is held, such as aiming down sights
+    pub enable_while: Option<F>,
+    /// Inverts the vertical direction
+    pub invert_pitch: bool
+}
+impl<F> Default for GyroAim<F> {
+    fn default() -> Self {
+        Self {
+            min_sensitivity: 1.0,
+            max_sensitivity: 2.0,
+            min_speed: 0.0,
+            max_speed: 120.0,
+            tightening: 5.0,
+            gravity_correction: true,
+            enable_while: None,
+            invert_pitch: false
+        }
+    }
+}
+impl<F> GyroAim<F> {
+    /// Turns a gyro reading in degrees per second into the yaw and pitch speed of the camera
+    pub(crate) fn process(&self, gyro: [f32; 3], gravity: [f32; 3]) -> (f32, f32) {
+        let [pitch, yaw, roll] = gyro;
+        let yaw = if self.gravity_correction {
+            // player space, from gyrowiki.jibbsmart.com
+            let len = (gravity[0] * gravity[0] + gravity[1] * gravity[1] + gravity[2] * gravity[2]).sqrt();
+            if len > f32::EPSILON {
+                let world_yaw = -(gravity[1] * yaw + gravity[2] * roll) / len;
+                world_yaw.signum() * (world_yaw.abs() * 1.41).min((yaw * yaw + roll * roll).sqrt())
+            } else { yaw }
+        } else { yaw };
+        let speed = (yaw * yaw + pitch * pitch).sqrt();
+        let tightening = if speed < self.tightening { speed / self.tightening } else { 1.0 };
+        let t = if self.max_speed > self.min_speed {
+            ((speed - self.min_speed) / (self.max_speed - self.min_speed)).clamp(0.0, 1.0)
+        } else { 0.0 };
+        let sensitivity = self.min_sensitivity + (self.max_sensitivity - self.min_sensitivity) * t;
+        let scale = sensitivity * tightening;
+        let pitch = if self.invert_pitch { -pitch } else { pitch };
+        (yaw * scale, pitch * scale)
+    }
+}
@@ -0,0 +1,81 @@
+use gilrs::{ff::*, GamepadId, Gilrs};
+use std::{collections::HashMap, time::Duration};
+use crate::GamepadButton;
+/// Rumble and trigger effects for one gamepad, see `InputMap::haptics`. Effects that the gamepad
+/// or backend dont support do nothing and return `false`, so they can be used without checking.
+pub struct Haptics<'a> {
+    pub(crate) gilrs: Option<&'a mut Gilrs>,
+    pub(crate) effects: &'a mut HashMap<GamepadId, Effect>,
+    pub(crate) id: GamepadId
+}
+/// An effect for the adaptive triggers of gamepads such as the DualSense. Positions and strengths
+/// go from 0 to 1
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum TriggerEffect {
+    #[default]
+    Off,
+    /// Pushes back with the same strength from `start` onwards
+    Resistance { start: f32, strength: f32 },
+    /// Resists between `start` and `end` then gives way, like pulling the trigger of a gun
+    Weapon { start: f32, end: f32, strength: f32 },
+    /// Vibrates from `start` onwards at a frequency in hz
+    Vibration { start: f32, amplitude: f32, frequency: f32 }
+}
+/// Rumble with separate low and high frequency motors, as in the HD rumble of the Switch and the
+/// voice coil motors of the DualSense. Amplitudes go from 0 to 1
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct HdRumble {
+    pub low_frequency: f32,
+    pub low_amplitude: f32,
+    pub high_frequency: f32,
+    pub high_amplitude: f32
+}
+impl Haptics<'_> {
+    /// Whether the gamepad can rumble
+    pub fn supported(&self) -> bool {
+        self.gilrs.as_ref().and_then(|gilrs| gilrs.connected_gamepad(self.id))
+            .is_some_and(|gamepad| gamepad.is_ff_supported())
+    }
+    /// Rumbles the strong low frequency and weak high frequency motors, from 0 to 1, for a
+    /// duration. Replaces the last rumble played on this gamepad.
+    pub fn rumble(&mut self, strong: f32, weak: f32, duration: Duration) -> bool {
+        if !self.supported() { return false }
+        let Some(gilrs) = self.gilrs.as_mut() else { return false };
+        let ms = duration.as_millis().min(u32::MAX as u128) as u32;
+        let scheduling = Replay { play_for: Ticks::from_ms(ms), ..Default::default() };
+        let magnitude = |val: f32| (val.clamp(0.0, 1.0) * u16::MAX as f32) as u16;
+        let effect = EffectBuilder::new()
+            .add_effect(BaseEffect {
+                kind: BaseEffectType::Strong { magnitude: magnitude(strong) },
+                scheduling, ..Default::default()
+            })
+            .add_effect(BaseEffect {
+                kind: BaseEffectType::Weak { magnitude: magnitude(weak) },
+                scheduling, ..Default::default()
+            })
+            .repeat(Repeat::For(Ticks::from_ms(ms)))
+            .gamepads(&[self.id])
+            .finish(gilrs);
+        let Ok(effect) = effect else { return false };
+        if effect.play().is_err() { return false }
+        // dropping an effect stops it, so its kept until replaced
+        self.effects.insert(self.id, effect);
+        true
+    }
+    /// Plays HD rumble. No backend supports the frequencies yet, so this falls back to `rumble`
+    /// with the low and high amplitudes.
+    pub fn hd_rumble(&mut self, rumble: HdRumble, duration: Duration) -> bool {
+        self.rumble(rumble.low_amplitude, rumble.high_amplitude, duration)
+    }
+    /// Stops the rumble playing on this gamepad
+    pub fn stop(&mut self) {
+        if let Some(effect) = self.effects.remove(&self.id) { let _ = effect.stop(); }
+    }
+    /// Sets the effect of an adaptive trigger, `LeftTrigger2` or `RightTrigger2`. gilrs cant send
+    /// trigger effects yet so this always returns `false`, but lets games use them now and get
+    /// them once a backend supports it.
+    pub fn set_trigger_effect(&mut self, trigger: GamepadButton, effect: TriggerEffect) -> bool {
+        let _ = (trigger, effect);
+        false
+    }
+}
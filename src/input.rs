@@ -1,24 +1,339 @@
 use winit::{
-    dpi::PhysicalPosition,
+    dpi::{PhysicalPosition, PhysicalSize},
+    keyboard::{Key, KeyCode, PhysicalKey, ModifiersState},
     event::*,
+    window::{CursorGrabMode, CursorIcon, Window},
+    error::ExternalError,
 };
 use crate::input_code::*;
-use std::collections::HashMap;
-use std::{cmp::Eq, hash::Hash};
-#[cfg(not(feature = "glium-types"))]
+use crate::latency::*;
+use crate::processor::*;
+use crate::action_expr::*;
+use crate::action::*;
+use crate::binds::*;
+use crate::settings::{InputSettings, FilterState};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::{borrow::Borrow, cmp::Eq, hash::Hash, path::PathBuf};
+use web_time::{Duration, Instant};
+use std::sync::{mpsc::{channel, Receiver, Sender}, Mutex};
+#[cfg(not(any(feature = "glium-types", feature = "glam")))]
 type Vec2 = (f32, f32);
 #[cfg(feature = "glium-types")]
 type Vec2 = glium_types::vectors::Vec2;
+#[cfg(feature = "glam")]
+type Vec2 = glam::Vec2;
 fn v(a: f32, b: f32) -> Vec2 {
-    #[cfg(not(feature = "glium-types"))]
+    #[cfg(not(any(feature = "glium-types", feature = "glam")))]
     { (a, b) }
-    #[cfg(feature = "glium-types")]
+    #[cfg(any(feature = "glium-types", feature = "glam"))]
     { Vec2::new(a, b) }
 }
+/// the parts of a `Vec2`
+fn xy(vec: Vec2) -> (f32, f32) {
+    #[cfg(not(any(feature = "glium-types", feature = "glam")))]
+    { vec }
+    #[cfg(any(feature = "glium-types", feature = "glam"))]
+    { (vec.x, vec.y) }
+}
+#[cfg(not(any(feature = "glium-types", feature = "glam")))]
+type Vec3 = (f32, f32, f32);
+#[cfg(feature = "glium-types")]
+type Vec3 = glium_types::vectors::Vec3;
+#[cfg(feature = "glam")]
+type Vec3 = glam::Vec3;
+fn v3(a: f32, b: f32, c: f32) -> Vec3 {
+    #[cfg(not(any(feature = "glium-types", feature = "glam")))]
+    { (a, b, c) }
+    #[cfg(any(feature = "glium-types", feature = "glam"))]
+    { Vec3::new(a, b, c) }
+}
+/// The mouse movement, scroll and gesture codes, which `init` sets back to 0
+const LOOP_AXES: [InputCode; 17] = [
+    InputCode::MOUSE_MOVE_X_POS, InputCode::MOUSE_MOVE_X_NEG,
+    InputCode::MOUSE_MOVE_Y_POS, InputCode::MOUSE_MOVE_Y_NEG,
+    InputCode::MOUSE_SCROLL_POS, InputCode::MOUSE_SCROLL_NEG,
+    InputCode::MOUSE_SCROLL_X_POS, InputCode::MOUSE_SCROLL_X_NEG,
+    InputCode::PINCH_OUT, InputCode::PINCH_IN, InputCode::ROTATE_CCW, InputCode::ROTATE_CW,
+    any_device(DeviceInput::PanX(AxisSign::Pos)), any_device(DeviceInput::PanX(AxisSign::Neg)),
+    any_device(DeviceInput::PanY(AxisSign::Pos)), any_device(DeviceInput::PanY(AxisSign::Neg)),
+    any_device(DeviceInput::DoubleTap)
+];
+/// Where the scroll codes are in `LOOP_AXES`, they slow down instead of resetting
+const SCROLL_AXES: std::ops::Range<usize> = 4..8;
+/// The index of the double tap code in `LOOP_AXES`
+const DOUBLE_TAP_AXIS: usize = 16;
+const fn any_device(input: DeviceInput) -> InputCode {
+    InputCode::Device { id: SpecifyDevice::Any, input }
+}
 
+/// When an action was last pressed and released and if that press was a double press
+#[derive(Debug, Clone, Copy)]
+struct Timing { pressed_at: Instant, released_at: Option<Instant>, double: bool }
+/// The actions a tap and hold bind drives, see `InputMap::set_tap_hold`
+#[derive(Debug, Clone)]
+struct TapHold<F> { tap: F, hold: F, threshold: Duration }
+/// A change to an action sent to subscribers, see `InputMap::subscribe`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ActionEvent<F> {
+    Pressed(F, f32),
+    Released(F),
+    /// The value changed without being pressed or released, such as a stick moving
+    Changed(F, f32)
+}
+/// A copy of the state of an input map at one point, see `InputMap::snapshot`. It can be held
+/// for a whole frame, sent to other threads and compared in replays and tests
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputState<F: Hash + Eq> {
+    /// The value of each action and if its pressing, pressed and released
+    actions: HashMap<F, (f32, bool, bool, bool)>,
+    cursor_pos: PhysicalPosition<f64>,
+    /// Mouse movement with `mouse_scale` applied
+    mouse_delta: (f32, f32)
+}
+impl<F: Hash + Eq + Clone> InputState<F> {
+    fn get(&self, action: &F) -> (f32, bool, bool, bool) {
+        self.actions.get(action).copied().unwrap_or_default()
+    }
+    pub fn action_val(&self, action: F) -> f32 { self.get(&action).0 }
+    pub fn pressing(&self, action: F) -> bool { self.get(&action).1 }
+    pub fn pressed(&self, action: F) -> bool { self.get(&action).2 }
+    pub fn released(&self, action: F) -> bool { self.get(&action).3 }
+    pub fn axis(&self, pos: F, neg: F) -> f32 {
+        self.action_val(pos) - self.action_val(neg)
+    }
+    pub fn dir(&self, pos_x: F, neg_x: F, pos_y: F, neg_y: F) -> Vec2 {
+        v(self.axis(pos_x, neg_x), self.axis(pos_y, neg_y))
+    }
+    pub fn mouse_pos(&self) -> Vec2 {
+        v(self.cursor_pos.x as f32, self.cursor_pos.y as f32)
+    }
+    pub fn mouse_delta(&self) -> Vec2 {
+        v(self.mouse_delta.0, self.mouse_delta.1)
+    }
+}
+/// The state of an input map at the end of a loop, see `InputMap::history`
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryFrame<F: Hash + Eq> {
+    /// When `init` was called at the end of the loop
+    pub time: Instant,
+    pub state: InputState<F>
+}
+/// The actions that changed this loop, see `InputMap::changes`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameChanges<F> {
+    pub pressed: Vec<F>,
+    pub released: Vec<F>,
+    /// Every action whose value changed, including presses and releases
+    pub changed: Vec<F>
+}
+/// When a callback runs, see `InputMap::on_pressed`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CallbackTrigger { Pressed, Released, Changed }
+type ActionCallback = Box<dyn FnMut(f32) + Send>;
+/// Which devices an input map listens to, see `InputMap::device_filter`
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum DeviceFilter {
+    #[default]
+    All,
+    /// Only listens to these devices, such as to split two keyboards between two players
+    Only(HashSet<DeviceId>),
+    /// Ignores these devices, such as a macro pad that shows up as a second keyboard
+    Ignore(HashSet<DeviceId>)
+}
+impl DeviceFilter {
+    pub fn allows(&self, id: DeviceId) -> bool {
+        match self {
+            Self::All => true,
+            Self::Only(ids) => ids.contains(&id),
+            Self::Ignore(ids) => !ids.contains(&id)
+        }
+    }
+}
+/// The kind of device an input came from, see `InputMap::last_input_source`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum InputSource {
+    Keyboard,
+    /// The mouse, including touchpad gestures
+    Mouse,
+    Touch,
+    /// Other device axis, such as a 3D mouse, see `DeviceInput::DeviceAxis`
+    DeviceAxis,
+    #[cfg(feature = "gamepad")]
+    Gamepad(gilrs::GamepadId)
+}
+/// Identifies a sequence added with `InputMap::add_sequence`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct SequenceId(usize);
+/// An ordered list of actions to be pressed and how far through it the player is
+#[derive(Debug, Clone)]
+struct Sequence<F> { steps: Vec<(F, Duration)>, progress: usize, last_step: Instant, completed: bool }
+/// Text typed or a key used for editing text, in the order they happened. See
+/// `InputMap::drain_text`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TextEvent {
+    /// Text typed in a row
+    Text(String),
+    /// Backspace, Delete, Enter, Tab, Escape, an arrow key, Home or End. Repeats when held down.
+    Key(KeyCode)
+}
+/// What an action is doing this loop, see `InputMap::state`
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ActionState {
+    /// Isnt being pressed
+    #[default]
+    None,
+    /// Was just pressed
+    Started,
+    /// Is being held down
+    Ongoing,
+    /// Was just let go of
+    Completed,
+    /// Was just released without being let go of, such as by losing a rollover or `reset`
+    Canceled
+}
+/// An input code that `InputMap::absorb` added actions to when it was already bound to others
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindConflict<F> {
+    pub code: InputCode,
+    /// What the input code was bound to before
+    pub existing: Vec<F>,
+    /// What was added to the input code
+    pub added: Vec<F>
+}
+/// A mouse drag, see `InputMap::drag`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Drag {
+    /// Where the mouse was when the button was pressed
+    pub start: Vec2,
+    /// How far the mouse has moved since the button was pressed
+    pub delta: Vec2,
+    /// True on the loop the button was released, the drag wont be returned after that
+    pub ended: bool
+}
+/// Where a mouse button was pressed, if it moved past the drag threshold and if it was released
+#[derive(Debug, Clone, Copy)]
+struct DragState { start: PhysicalPosition<f64>, started: bool, ended: bool }
+/// A finger on a touch screen, see `InputMap::touches`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Touch {
+    pub id: u64,
+    /// Where the touch is in physical pixels
+    pub pos: Vec2,
+    /// Where the touch started
+    pub start: Vec2,
+    /// `Ended` or `Cancelled` on the loop the touch was lifted, it wont be returned after that
+    pub phase: TouchPhase,
+    /// How hard the screen is being pressed from 0-1, if the device supports it
+    pub force: Option<f32>
+}
+/// Identifies a pointer, see `InputMap::pointers`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum PointerId {
+    Mouse,
+    /// A finger or pen by its touch id
+    Touch(u64)
+}
+/// The mouse or a touch, so mouse and touch screens can be handled the same way. See
+/// `InputMap::pointers`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pointer {
+    pub id: PointerId,
+    /// Where the pointer is in physical pixels
+    pub pos: Vec2,
+    /// If the left mouse button is held, or if the touch hasnt been lifted
+    pub pressed: bool,
+    /// How hard the pointer is pressing from 0-1, if its a touch on a device that supports it
+    pub pressure: Option<f32>
+}
+/// Where a touch started and where it is now
+#[derive(Debug, Clone, Copy)]
+struct TouchState {
+    /// if the touch started while the pointer was captured by UI
+    captured: bool,
+    start: PhysicalPosition<f64>,
+    pos: PhysicalPosition<f64>,
+    phase: TouchPhase,
+    force: Option<f32>,
+    /// when the touch started, so the oldest one can become the primary touch
+    started_at: Instant
+}
+/// An on screen joystick controlled by touch, see `InputMap::add_virtual_joystick`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VirtualJoystick {
+    /// Touches starting here control the joystick, with the start of the touch as its center
+    pub region: TouchRegion,
+    /// How far in physical pixels the touch has to move from the center to fully press an action
+    pub radius: f32,
+    /// Drags the center along with the touch when it goes past `radius`, so moving back the other
+    /// way responds straight away
+    pub recenter: bool
+}
+/// The id and center of the touch controlling a virtual joystick
+type JoystickTouch = (u64, PhysicalPosition<f64>);
+/// Identifies a joystick added with `InputMap::add_virtual_joystick`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct JoystickId(usize);
+/// Identifies a layer of binds, see `InputMap::add_layer`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct LayerId(usize);
+/// A part of the screen relative to the window size, where (0, 0) is the top left and (1, 1) is
+/// the bottom right. See `InputMap::add_touch_region`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TouchRegion { pub min_x: f32, pub min_y: f32, pub max_x: f32, pub max_y: f32 }
+impl TouchRegion {
+    pub const FULL:       Self = Self::new(0.0, 0.0, 1.0, 1.0);
+    pub const LEFT_HALF:  Self = Self::new(0.0, 0.0, 0.5, 1.0);
+    pub const RIGHT_HALF: Self = Self::new(0.5, 0.0, 1.0, 1.0);
+    pub const fn new(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> Self {
+        Self { min_x, min_y, max_x, max_y }
+    }
+    pub fn contains(&self, (x, y): (f32, f32)) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+}
+/// Where a gamepad axis rests and how far it reaches each way, see `InputMap::axis_calibration`
+#[cfg(feature = "gamepad")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AxisCalibration { pub center: f32, pub min: f32, pub max: f32 }
+#[cfg(feature = "gamepad")]
+impl Default for AxisCalibration {
+    fn default() -> Self {
+        Self { center: 0.0, min: -1.0, max: 1.0 }
+    }
+}
+#[cfg(feature = "gamepad")]
+impl AxisCalibration {
+    /// Moves the value so the center is 0 and rescales it so min and max become -1 and 1
+    pub fn apply(self, val: f32) -> f32 {
+        let offset = val - self.center;
+        let range = if offset >= 0.0 { self.max - self.center } else { self.center - self.min };
+        if range <= f32::EPSILON { return 0.0 }
+        (offset / range).clamp(-1.0, 1.0)
+    }
+}
+#[cfg(feature = "gamepad")]
+const STICK_AXES: [GamepadAxis; 4] = [
+    GamepadAxis::LeftStickX, GamepadAxis::LeftStickY, GamepadAxis::RightStickX, GamepadAxis::RightStickY
+];
+/// How two opposing actions are resolved when both are being pressed. See
+/// `InputMap::set_rollover`
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Rollover {
+    /// Both actions stay pressed
+    #[default]
+    Neutral,
+    /// The most recently pressed action wins and the other is released until the winner is let
+    /// go or the other is pressed again, commonly called snap tap
+    LastInputPriority,
+    /// The first pressed action wins and the other is ignored until the winner is let go
+    FirstInputPriority
+}
 
 /// A struct that handles all your input needs once you've hooked it up to winit and gilrs.
 /// ```
+/// # use winit::{window::*, application::*, event_loop::*, event::*};
+/// # use winit_input_map::*;
+/// # use gilrs::Gilrs;
 /// struct App {
 ///     window: Option<Window>,
 ///     input: InputMap<()>,
@@ -44,42 +359,468 @@ fn v(a: f32, b: f32) -> Vec2 {
 ///     }
 /// }
 /// ```
-pub struct InputMap<F: Hash + Eq + Clone + Copy> {
-    /// Stores what each input code is bound to
-    pub binds: HashMap<InputCode, Vec<F>>,
+/// Actions only need to be `Clone`, so names loaded from config or mod files work as actions too
+/// ```
+/// # use winit_input_map::*;
+/// # use winit::keyboard::KeyCode;
+/// let jump = "jump".to_string();
+/// let input = InputMap::new(&[(jump.clone(), vec![KeyCode::Space.into()])]);
+/// if input.pressed(jump) { /* jump */ }
+/// ```
+pub struct InputMap<F: Hash + Eq + Clone> {
+    /// Stores what each input code is bound to. Prefer `bind` and `unbind` for changing it, which
+    /// keep the cache of which mouse and gesture codes are bound up to date. Other changes are
+    /// noticed when they change how many input codes are bound
+    pub binds: Binds<F>,
     /// f32 is current val, 1st bool is pressed and 2nd bool is released.
-    action_val: HashMap<F, (f32, bool, bool)>,
-    /// The mouse position
+    action_val: ActionVals<F>,
+    /// The same as `action_val` but for every input code that has been seen, bound or not
+    code_vals: HashMap<InputCode, (f32, bool, bool), BindHasher>,
+    /// The opposing action and rollover policy of each action in a rollover pair
+    rollover: HashMap<F, (F, Rollover)>,
+    /// Actions released this loop without being let go of
+    canceled: HashSet<F>,
+    /// If each action was pressed and released since `consume_tick` was last called
+    tick_edges: HashMap<F, (bool, bool)>,
+    /// Press timings of each action that has been pressed
+    timing: HashMap<F, Timing>,
+    /// Actions that drive a tap and a hold action
+    tap_hold: HashMap<F, TapHold<F>>,
+    /// Tap actions that were pressed this loop and need releasing
+    tapped: Vec<F>,
+    /// Actions that take their value from the opposite sign of the axes theyre bound to
+    inverted: HashSet<F>,
+    /// Binds that take their value from the opposite sign of their axis
+    inverted_binds: HashSet<(InputCode, F)>,
+    /// Input codes that are treated as other input codes before looking up binds
+    remaps: HashMap<InputCode, InputCode>,
+    /// Processors run on the value of binds, by input code and action
+    processors: HashMap<(InputCode, F), Vec<Processor>>,
+    /// The state of each action used as a toggle
+    toggles: HashMap<F, bool>,
+    /// Sequences of actions being matched against presses
+    sequences: Vec<Sequence<F>>,
+    /// Callbacks run by `dispatch`
+    callbacks: Vec<(F, CallbackTrigger, ActionCallback)>,
+    /// The value of each action when `dispatch` was last called, for `on_changed`
+    dispatched: HashMap<F, f32>,
+    /// Inputs with a value below this dont count towards `idle_time`, so stick noise doesnt keep
+    /// the player active
+    pub idle_threshold: f32,
+    /// Kinds of input that dont count towards `idle_time`. Motion by default as accelerometers
+    /// always report gravity
+    pub idle_ignore: Vec<InputKind>,
+    /// When an input last counted towards `idle_time`
+    last_input: Instant,
+    /// How many loops `history` keeps, 0 by default which turns it off
+    pub history_len: usize,
+    /// The last `history_len` loops, oldest first
+    history: VecDeque<HistoryFrame<F>>,
+    /// The exclusive group and priority of actions, see `add_exclusive_group`
+    exclusive: HashMap<F, (usize, i32)>,
+    /// Actions that dont receive input, see `set_action_active`
+    inactive: HashSet<F>,
+    /// Actions driven by a 2D direction, see `set_axis_2d`
+    axes_2d: HashMap<F, crate::Axis2d>,
+    /// The value of each input of 2D actions after remaps and processors
+    axis_2d_vals: HashMap<(F, InputCode), f32>,
+    /// Actions driven by expressions over other actions, see `add_composite`
+    composites: Vec<(F, ActionExpr<F>)>,
+    /// The input held for each layer and the binds used while its held, see `add_layer`
+    layers: Vec<(InputCode, Binds<F>)>,
+    /// Input codes pressed on a layer and the layer they were pressed on
+    held_layered: HashMap<InputCode, usize>,
+    /// The cooldown of each action and when its last press was accepted, see `set_cooldown`
+    cooldowns: HashMap<F, (Duration, Option<Instant>)>,
+    /// Actions whose value changed since `init` was last called
+    changed: HashSet<F>,
+    /// Channels action events are sent to, see `subscribe`
+    subscribers: Vec<Sender<ActionEvent<F>>>,
+    /// Partial steps and when they were last checked for each axis used by `scrub`
+    scrubs: HashMap<(F, F), (f32, Instant)>,
+    /// Cursor icons to show while actions are being pressed
+    cursor_icons: HashMap<F, CursorIcon>,
+    /// The last cursor icon set by `apply_cursor`
+    applied_cursor: Option<CursorIcon>,
+    /// The mouse position. This is kept after the cursor leaves the window, see
+    /// `mouse_pos_in_window`
     pub mouse_pos: Vec2,
+    /// The mouse position as winit reported it
+    cursor_pos: PhysicalPosition<f64>,
+    /// The size of the window, updated when it's resized. Used for `mouse_pos_normalized`
+    pub window_size: PhysicalSize<u32>,
+    /// The scale factor of the window, updated when it changes. Used for `mouse_pos_logical`
+    pub scale_factor: f64,
+    /// Gilrs instance owned by the input map, set up through `with_gamepads`. `None` if it wasnt
+    /// set up or gilrs failed to start
+    #[cfg(feature = "gamepad")]
+    pub gilrs: Option<gilrs::Gilrs>,
+    /// SDL_GameControllerDB mappings added to the owned `Gilrs`, see `load_controller_mappings`
+    #[cfg(feature = "gamepad")]
+    controller_mappings: String,
+    /// The value each gamepads analog triggers report at rest, by gamepad uuid. Trigger values are
+    /// offset and rescaled so the rest value becomes 0. Its taken by `start_calibration` and by
+    /// `learn_trigger_rest`, save and restore this to keep it between runs.
+    #[cfg(feature = "gamepad")]
+    pub trigger_rest: HashMap<([u8; 16], GamepadButton), f32>,
+    /// Takes where the triggers are when a gamepad connects as their rest value, for gamepads that
+    /// dont have one yet. Some drivers report 0.5 at rest which would otherwise make triggers
+    /// count as always pressed. Off by default, as a trigger held while connecting would be kept
+    /// as its rest value.
+    #[cfg(feature = "gamepad")]
+    pub learn_trigger_rest: bool,
+    /// Calibration of each connected gamepads sticks, applied before processors such as
+    /// deadzones. Its by gamepad id so two gamepads of the same model each get their own, and it
+    /// moves with the gamepad when it reconnects, see `reassign_gamepad` and `start_calibration`
+    #[cfg(feature = "gamepad")]
+    pub axis_calibration: HashMap<(gilrs::GamepadId, GamepadAxis), AxisCalibration>,
+    /// Takes where the sticks are when a gamepad is first seen as their center, fixing drift on
+    /// worn gamepads. Skipped for gamepads that already have calibration and for sticks that
+    /// arent near the center. Off by default, as the first stick reading is usually the player
+    /// starting to move it, which would be kept as an offset. `start_calibration` is more
+    /// reliable.
+    #[cfg(feature = "gamepad")]
+    pub auto_center: bool,
+    /// Gamepads being calibrated and if their center has been taken
+    #[cfg(feature = "gamepad")]
+    calibrating: HashMap<gilrs::GamepadId, bool>,
+    /// Gamepads that have been auto centered
+    #[cfg(feature = "gamepad")]
+    auto_centered: HashSet<gilrs::GamepadId>,
+    /// Ids of disconnected gamepads by their uuid, used to give binds back to them when they
+    /// reconnect with a new id
+    #[cfg(feature = "gamepad")]
+    disconnected_gamepads: HashMap<[u8; 16], Vec<gilrs::GamepadId>>,
+    /// Binds used instead of `binds` for events from a gamepad. Follows the gamepad to its new id
+    /// when it reconnects
+    #[cfg(feature = "gamepad")]
+    gamepad_binds: HashMap<gilrs::GamepadId, Binds<F>>,
+    /// The uuid of every gamepad seen, so `gamepad_binds` can be saved per device
+    #[cfg(feature = "gamepad")]
+    gamepad_uuids: HashMap<gilrs::GamepadId, [u8; 16]>,
+    /// Binds from `restore_gamepad_binds` for gamepads that havent connected yet
+    #[cfg(feature = "gamepad")]
+    pending_gamepad_binds: Vec<SavedGamepadBinds<F>>,
+    /// The last motion sensor reading of each gamepad
+    #[cfg(feature = "gamepad")]
+    motion: HashMap<gilrs::GamepadId, Motion>,
+    /// The rumble playing on each gamepad, kept as dropping it stops it
+    #[cfg(feature = "haptics")]
+    rumble: HashMap<gilrs::GamepadId, gilrs::ff::Effect>,
+    /// The last touchpad state of each gamepad
+    #[cfg(feature = "gamepad")]
+    touchpads: HashMap<gilrs::GamepadId, Touchpad>,
+    /// Settings for turning the gyro into camera movement, see `set_gyro_aim`
+    #[cfg(feature = "gamepad")]
+    gyro_aim: Option<crate::GyroAim<F>>,
+    /// Gravity smoothed from the accelerometer, when the last motion reading was and how far the
+    /// camera should turn this loop from the gyro
+    #[cfg(feature = "gamepad")]
+    gyro_state: ([f32; 3], Option<Instant>, (f32, f32)),
+    /// The flick stick, the last angle of its stick and how far to turn this loop
+    #[cfg(feature = "gamepad")]
+    flick_stick: Option<(crate::FlickStick<F>, Option<f32>, f32)>,
+    /// The family of each gamepad that has sent an event, for button prompts
+    #[cfg(feature = "prompts")]
+    gamepad_families: HashMap<gilrs::GamepadId, crate::GamepadFamily>,
+    /// Mouse buttons being held down and where they were pressed
+    drags: HashMap<MouseButton, DragState>,
+    /// Fingers on the touch screen by id
+    touches: HashMap<u64, TouchState>,
+    /// The first touch still held, used as the primary pointer
+    primary_touch: Option<u64>,
+    /// If the cursor is over the window
+    cursor_inside: bool,
+    /// The pressure and tilt of the last touch to report them, while its held
+    pen: Option<(f32, Option<f32>)>,
+    /// Parts of the screen that press an action while a touch that started in them is held
+    touch_regions: Vec<(TouchRegion, F)>,
+    /// Virtual joysticks and the id and center of the touch controlling them
+    joysticks: Vec<(VirtualJoystick, Option<JoystickTouch>)>,
+    /// The unscaled mouse movement this loop, kept at full precision
+    mouse_delta: (f64, f64),
+    /// The unscaled scroll this loop in lines, with smooth scrolling turned into lines by
+    /// `scroll_step_pixels`
+    scroll_delta: (f64, f64),
+    /// Which of `LOOP_AXES` have been set since the last `init`
+    loop_axes_moved: [bool; 17],
+    /// Which of `LOOP_AXES` are bound, so unbound ones can skip looking up binds
+    loop_axes_bound: [bool; 17],
+    /// Device axis codes that have been set since the last `init`
+    device_axes_moved: Vec<InputCode>,
+    /// If any `DeviceInput::DeviceAxis` is bound
+    device_axes_bound: bool,
+    /// Device axes that report a position rather than movement, see `set_device_axis_absolute`
+    absolute_axes: HashSet<AxisId>,
+    /// The generation of `binds` when `loop_axes_bound` was made, or `None` if the layers have
+    /// changed since
+    bind_cache_gen: Option<u64>,
+    /// The smoothing state of each mouse axis, see `InputSettings::mouse_filter_x`
+    mouse_filters: [FilterState; 2],
+    /// When `mouse_filters` were last updated, by mouse movement or `init`
+    last_mouse_filter: Instant,
     /// The last input event, even if it isn't in the binds. Useful for handling rebinding
     pub recently_pressed: Option<InputCode>,
+    /// The device `recently_pressed` came from, if known
+    recent_device: Option<DeviceId>,
+    /// The device of the event being handled, if known
+    event_device: Option<DeviceId>,
+    /// The kind of device of the event being handled
+    event_source: Option<InputSource>,
+    /// The kind of device that last pressed something
+    last_source: Option<InputSource>,
+    /// The kind of device that last pressed each action
+    action_sources: HashMap<F, InputSource>,
+    /// Which devices are listened to. Events from devices that aren't allowed are ignored. Device
+    /// events need to be passed in with `update_with_device_event_from` to be filtered.
+    pub device_filter: DeviceFilter,
+    /// Every input code pressed this loop in order and the value that pressed it
+    pressed_this_loop: Vec<(InputCode, f32)>,
+    /// The modifier keys currently held
+    modifiers: ModifiersState,
+    /// The logical key each physical key was when it was pressed, so they can be released even if
+    /// the layout or modifiers changed in between
+    held_logical: HashMap<PhysicalKey, InputCode>,
+    /// The input codes being held for each wildcard input code
+    wildcards: HashMap<InputCode, HashSet<InputCode>>,
+    /// What each physical key has typed without modifiers, used to show layout correct names
+    key_names: HashMap<PhysicalKey, String>,
+    /// Device inputs being held that matched a bind with modifiers when pressed
+    held_modified: HashMap<InputCode, InputCode>,
     /// The text typed this loop
     pub text_typed: Option<String>,
-    /// Since most values are from 0-1 reducing the mouse sensitivity will result in better
-    /// consistancy
+    /// Text typed and text editing keys pressed this loop
+    text_events: VecDeque<TextEvent>,
+    /// Files dropped on the window this loop
+    dropped_files: Vec<PathBuf>,
+    /// Files being dragged over the window
+    hovered_files: Vec<PathBuf>,
+    /// If keys that type text are kept from pressing actions, see `set_text_input_mode`
+    text_input_mode: bool,
+    /// Keeps reading keys and mouse buttons from device events while the window isnt focused, so
+    /// actions allowed with `set_background` can be pressed in the background, such as push to
+    /// talk. Mouse buttons are only sent as `DeviceInput::RawButton`. Off by default.
+    ///
+    /// winit only sends device events to an unfocused window if the event loop is told to, so
+    /// this also needs `event_loop.listen_device_events(DeviceEvents::Always)`.
+    pub background_input: bool,
+    /// Actions that can be pressed while the window isnt focused and `background_input` is on
+    background_actions: HashSet<F>,
+    /// If the window has focus
+    focused: bool,
+    /// Stops the keyboard from pressing actions while UI is using it. Text typed is still
+    /// recorded and keys can still be released. Usually set from
+    /// `egui::Context::wants_keyboard_input`
+    pub keyboard_captured: bool,
+    /// Stops the mouse, touches and gestures from pressing actions while UI is using them. The
+    /// mouse position, touches and pointers are still tracked and buttons can still be released.
+    /// Usually set from `egui::Context::wants_pointer_input`
+    pub pointer_captured: bool,
+    /// If the cursor is grabbed and hidden for mouse look, see `set_mouse_captured`
+    mouse_captured: bool,
+    /// If `reset` let go of the cursor while it was captured, so `apply_cursor` needs to release it
+    release_capture: bool,
+    /// The text being composed with an IME and where its cursor is
+    preedit: Option<(String, Option<(usize, usize)>)>,
+    /// Mouse, scroll, gyro and pan sensitivity, `press_sensitivity` and other settings a player
+    /// might want to change
+    pub settings: InputSettings,
+    /// Use `settings.set_mouse_scale` instead. Setting this still sets both mouse scales once the
+    /// next event or `init` comes in
+    #[deprecated(note = "use `settings.set_mouse_scale` instead")]
     pub mouse_scale: f32,
-    /// Since most values are from 0-1 reducing the scroll sensitivity will result in better
-    /// consistancy
+    /// Use `settings.scroll_scale` instead. Setting this still works once the next event or `init`
+    /// comes in
+    #[deprecated(note = "use `settings.scroll_scale` instead")]
     pub scroll_scale: f32,
-    /// The minimum value something has to be at to count as being pressed. Values over 1 will
-    /// result in regular buttons being unusable
-    pub press_sensitivity: f32
+    /// Use `settings.press_sensitivity` instead. Setting this still works once the next event or
+    /// `init` comes in
+    #[deprecated(note = "use `settings.press_sensitivity` instead")]
+    pub press_sensitivity: f32,
+    /// the deprecated fields as they were when last synced with `settings`, to tell if they were
+    /// set
+    synced_deprecated: [f32; 3],
+    /// The speed of each scroll axis as it slows down, in scroll per second
+    scroll_speed: [f32; 4],
+    /// The scroll each scroll axis was given by `init` as it slows down
+    scroll_carried: [f32; 4],
+    /// Scrolling that hasnt made up a whole step yet
+    scroll_step_partial: (f64, f64),
+    /// The scroll steps this loop
+    scroll_steps: (i32, i32),
+    /// How many pixels the mouse has to move while a button is held before it counts as a drag
+    pub drag_threshold: f32,
+    /// The cursor icon used by `apply_cursor` when no action with a cursor icon is being pressed
+    pub default_cursor: CursorIcon,
+    /// The maximum time between two presses for them to count as a double press, see
+    /// `double_pressed`. 300ms by default
+    pub double_press_time: Duration,
+    /// How long an action has to be held before `pressed_or_repeat` starts repeating, like the
+    /// delay before a held key starts typing again. 500ms by default
+    pub repeat_delay: Duration,
+    /// The time between each repeat of `pressed_or_repeat` once `repeat_delay` has passed. 50ms
+    /// by default
+    pub repeat_interval: Duration,
+    /// When `init` was last called
+    last_init: Instant,
+    /// When `init` was called before `last_init`, so repeats are counted over a whole loop
+    prev_init: Instant,
+    /// An action that calls `reset` when pressed, as a safety hatch for stuck binds. Binding it
+    /// to something with modifiers, like Ctrl+Shift+Escape, stops it being pressed by accident.
+    pub panic_action: Option<F>,
+    /// Latency measurements, if turned on
+    latency: Option<Mutex<Latency<F>>>
 }
-impl<F: Hash + Eq + Clone + Copy> Default for InputMap<F> {
+impl<F: Hash + Eq + Clone> Default for InputMap<F> {
+    #[allow(deprecated)]
     fn default() -> Self {
+        let settings = InputSettings::default();
         Self {
-            mouse_scale: 0.1,
-            press_sensitivity: 0.5,
-            scroll_scale:      0.1,
+            mouse_scale: settings.mouse_scale_x,
+            scroll_scale: settings.scroll_scale,
+            press_sensitivity: settings.press_sensitivity,
+            synced_deprecated: [
+                settings.mouse_scale_x, settings.scroll_scale, settings.press_sensitivity
+            ],
+            settings,
+            scroll_step_partial: (0.0, 0.0),
+            scroll_steps: (0, 0),
+            scroll_speed: [0.0; 4],
+            scroll_carried: [0.0; 4],
             mouse_pos: v(0.0, 0.0),
+            mouse_delta: (0.0, 0.0),
+            scroll_delta: (0.0, 0.0),
+            loop_axes_moved: [false; 17],
+            loop_axes_bound: [false; 17],
+            device_axes_moved: Vec::new(),
+            device_axes_bound: false,
+            absolute_axes: HashSet::new(),
+            bind_cache_gen: None,
+            mouse_filters: Default::default(),
+            last_mouse_filter: Instant::now(),
+            drags: HashMap::new(),
+            touches: HashMap::new(),
+            touch_regions: Vec::new(),
+            pen: None,
+            primary_touch: None,
+            cursor_inside: false,
+            joysticks: Vec::new(),
+            #[cfg(feature = "gamepad")]
+            gilrs: None,
+            #[cfg(feature = "gamepad")]
+            controller_mappings: String::new(),
+            #[cfg(feature = "gamepad")]
+            trigger_rest: HashMap::new(),
+            #[cfg(feature = "gamepad")]
+            learn_trigger_rest: false,
+            #[cfg(feature = "gamepad")]
+            axis_calibration: HashMap::new(),
+            #[cfg(feature = "gamepad")]
+            auto_center: false,
+            #[cfg(feature = "gamepad")]
+            calibrating: HashMap::new(),
+            #[cfg(feature = "gamepad")]
+            auto_centered: HashSet::new(),
+            #[cfg(feature = "gamepad")]
+            disconnected_gamepads: HashMap::new(),
+            #[cfg(feature = "gamepad")]
+            gamepad_binds: HashMap::new(),
+            #[cfg(feature = "gamepad")]
+            gamepad_uuids: HashMap::new(),
+            #[cfg(feature = "gamepad")]
+            pending_gamepad_binds: Vec::new(),
+            #[cfg(feature = "gamepad")]
+            motion: HashMap::new(),
+            #[cfg(feature = "gamepad")]
+            touchpads: HashMap::new(),
+            #[cfg(feature = "haptics")]
+            rumble: HashMap::new(),
+            #[cfg(feature = "gamepad")]
+            #[cfg(feature = "gamepad")]
+            gyro_aim: None,
+            #[cfg(feature = "gamepad")]
+            gyro_state: ([0.0, -1.0, 0.0], None, (0.0, 0.0)),
+            #[cfg(feature = "gamepad")]
+            flick_stick: None,
+            #[cfg(feature = "prompts")]
+            gamepad_families: HashMap::new(),
+            drag_threshold: 4.0,
+            cursor_pos: PhysicalPosition::new(0.0, 0.0),
+            window_size: PhysicalSize::new(0, 0),
+            scale_factor: 1.0,
             recently_pressed: None,
+            recent_device: None,
+            event_device: None,
+            event_source: None,
+            last_source: None,
+            action_sources: HashMap::new(),
+            device_filter: DeviceFilter::All,
+            pressed_this_loop: vec![],
             text_typed:    None,
-            binds:      HashMap::<InputCode,    Vec<F>>::new(),
-            action_val: HashMap::<F, (f32, bool, bool)>::new()
+            dropped_files: Vec::new(),
+            hovered_files: Vec::new(),
+            modifiers: ModifiersState::empty(),
+            held_modified: HashMap::new(),
+            held_logical: HashMap::new(),
+            key_names: HashMap::new(),
+            wildcards: HashMap::new(),
+            text_events: VecDeque::new(),
+            text_input_mode: false,
+            background_input: false,
+            background_actions: HashSet::new(),
+            focused: true,
+            keyboard_captured: false,
+            pointer_captured: false,
+            mouse_captured: false,
+            release_capture: false,
+            preedit: None,
+            binds:      Binds::default(),
+            action_val: ActionVals::Map(HashMap::new()),
+            code_vals: HashMap::default(),
+            rollover:   HashMap::<F, (F, Rollover)>::new(),
+            tick_edges: HashMap::<F, (bool, bool)>::new(),
+            canceled: HashSet::<F>::new(),
+            timing:     HashMap::<F, Timing>::new(),
+            tap_hold:   HashMap::<F, TapHold<F>>::new(),
+            tapped: vec![],
+            sequences: vec![],
+            callbacks: vec![],
+            dispatched: HashMap::new(),
+            subscribers: vec![],
+            changed: HashSet::new(),
+            cooldowns: HashMap::new(),
+            layers: Vec::new(),
+            composites: Vec::new(),
+            axes_2d: HashMap::new(),
+            axis_2d_vals: HashMap::new(),
+            held_layered: HashMap::new(),
+            exclusive: HashMap::new(),
+            inactive: HashSet::new(),
+            history_len: 0,
+            idle_threshold: 0.25,
+            idle_ignore: vec![InputKind::Motion],
+            last_input: Instant::now(),
+            history: VecDeque::new(),
+            toggles: HashMap::<F, bool>::new(),
+            processors: HashMap::<(InputCode, F), Vec<Processor>>::new(),
+            remaps: HashMap::new(),
+            inverted: HashSet::<F>::new(),
+            inverted_binds: HashSet::<(InputCode, F)>::new(),
+            scrubs: HashMap::<(F, F), (f32, Instant)>::new(),
+            cursor_icons: HashMap::<F, CursorIcon>::new(),
+            applied_cursor: None,
+            default_cursor: CursorIcon::Default,
+            double_press_time: Duration::from_millis(300),
+            repeat_delay: Duration::from_millis(500),
+            repeat_interval: Duration::from_millis(50),
+            last_init: Instant::now(),
+            prev_init: Instant::now(),
+            panic_action: None,
+            latency: None
         }
     }
 }
-impl<F: Hash + Eq + Clone + Copy> InputMap<F> {
+impl<F: Hash + Eq + Clone> InputMap<F> {
     /// Create new input system. It's recommended to use the `input_map!` macro to reduce boilerplate
     /// and increase readability.
     /// ```
@@ -94,225 +835,2430 @@ impl<F: Hash + Eq + Clone + Copy> InputMap<F> {
     ///     Neg
     /// }
     /// //doesnt have to be the same ordered as the enum.
-    /// let mut input = Input::new([
-    ///     (vec![Input::keycode(KeyCode::KeyW)], Forward),
-    ///     (vec![Input::keycode(KeyCode::KeyA)], Pos),
-    ///     (vec![Input::keycode(KeyCode::KeyS)], Back),
-    ///     (vec![Input::keycode(KeyCode::KeyD)], Neg)
+    /// let mut input = InputMap::new(&[
+    ///     (Forward, vec![KeyCode::KeyW.into()]),
+    ///     (Pos,     vec![KeyCode::KeyA.into()]),
+    ///     (Back,    vec![KeyCode::KeyS.into()]),
+    ///     (Neg,     vec![KeyCode::KeyD.into()])
     /// ]);
     /// ```
     pub fn new(binds: &[(F, Vec<InputCode>)]) -> Self {
         let mut result = Self::default();
-        for (i, binds) in binds {
-            for bind in binds {
-                result.mut_bind(*bind).push(*i);
-            }
-        }
-        result.binds.shrink_to_fit();
+        result.bind_many(binds);
+        result.binds.map_mut().shrink_to_fit();
         result
     }
+    /// Makes the input map create and own its own `Gilrs` instance so it doesnt need to be managed
+    /// separately. Use `update_gamepads` instead of `update_with_gilrs`. If gilrs fails to start
+    /// the input map will carry on without gamepads.
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// let mut input = input_map!().with_gamepads();
+    /// // in about_to_wait
+    /// input.update_gamepads();
+    /// ```
+    #[cfg(feature = "gamepad")]
+    pub fn with_gamepads(mut self) -> Self {
+        self.start_gilrs();
+        self
+    }
+    /// Stores the value of each action in a flat array instead of a hash map, so checking an action
+    /// doesnt need to hash it. Worth it when lots of actions are checked every frame, see `actions!`
+    pub fn with_dense_storage(mut self) -> Self where F: IndexedAction {
+        self.action_val = self.action_val.dense();
+        self
+    }
+    #[cfg(feature = "gamepad")]
+    fn start_gilrs(&mut self) {
+        let gilrs = gilrs::GilrsBuilder::new().add_mappings(&self.controller_mappings).build();
+        self.gilrs = match gilrs {
+            Ok(gilrs) => Some(gilrs),
+            // gilrs still gives a working dummy instance on unsupported platforms
+            Err(gilrs::Error::NotImplemented(gilrs)) => Some(gilrs),
+            Err(_) => None
+        };
+    }
+    /// Loads SDL_GameControllerDB mapping strings, one per line, so gamepads gilrs doesnt know
+    /// map to the standard layout. Gilrs only reads mappings when it starts, so this restarts the
+    /// owned `Gilrs` which reconnects every gamepad with a new id. Binds, calibration and the rest
+    /// kept for each gamepad are moved to its new id by matching uuids, and dropped for gamepads
+    /// that dont come back. Rumble is stopped and held gamepad inputs are let go. Best called
+    /// right after `with_gamepads`.
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// let mut input = input_map!().with_gamepads();
+    /// input.load_controller_mappings(&std::fs::read_to_string("gamecontrollerdb.txt").unwrap());
+    /// ```
+    #[cfg(feature = "gamepad")]
+    pub fn load_controller_mappings(&mut self, mappings: &str) {
+        self.controller_mappings.push_str(mappings);
+        self.controller_mappings.push('\n');
+        let Some(gilrs) = &self.gilrs else { return };
+        let old: Vec<_> = gilrs.gamepads().map(|(id, gamepad)| (id, gamepad.uuid())).collect();
+        self.start_gilrs();
+        let mut new: Vec<_> = self.gilrs.iter()
+            .flat_map(|gilrs| gilrs.gamepads().map(|(id, gamepad)| (id, gamepad.uuid()))).collect();
+        let mut ids = HashMap::new();
+        for (old_id, uuid) in old {
+            let Some(i) = new.iter().position(|&(_, u)| u == uuid) else { continue };
+            ids.insert(old_id, new.remove(i).0);
+        }
+        #[cfg(feature = "haptics")]
+        self.rumble.clear();
+        self.disconnected_gamepads.clear();
+        self.code_vals.retain(|code, _| {
+            !matches!(code, InputCode::Gamepad { id: SpecifyGamepad::Id(_), .. })
+        });
+        self.remap_gamepads(&ids, false);
+    }
     /// Use if you dont want to have any actions and binds. Will still have access to everything else.
     pub fn empty() -> InputMap<()> {
         InputMap::<()>::default()
     }
+    /// Merges the binds of another input map into this one, along with its rollover pairs, tap
+    /// and hold actions, toggles, cursor icons, thresholds, processors, remaps, cooldowns,
+    /// exclusive groups, 2D axes, composite actions, layers and per gamepad model binds where
+    /// this map doesnt already have them. Useful for layering binds shipped with mods or DLC onto
+    /// the players binds. Layers on the same input are merged, so `LayerId`s from the other map
+    /// dont apply to this one. `settings` and sequences stay as they are. Returns input codes that
+    /// were already bound to other actions.
+    pub fn absorb(&mut self, other: InputMap<F>) -> Vec<BindConflict<F>> {
+        let mut conflicts = vec![];
+        for (code, actions) in other.binds {
+            let binds = self.binds.map_mut().entry(code).or_default();
+            let existing = binds.to_vec();
+            let added: Vec<F> = actions.into_iter().filter(|a| !existing.contains(a)).collect();
+            binds.extend(added.iter().cloned());
+            if !existing.is_empty() && !added.is_empty() {
+                conflicts.push(BindConflict { code, existing, added });
+            }
+        }
+        for (action, rollover) in other.rollover {
+            self.rollover.entry(action).or_insert(rollover);
+        }
+        for (source, tap_hold) in other.tap_hold {
+            self.tap_hold.entry(source).or_insert(tap_hold);
+        }
+        for (action, toggled) in other.toggles {
+            self.toggles.entry(action).or_insert(toggled);
+        }
+        for (action, icon) in other.cursor_icons {
+            self.cursor_icons.entry(action).or_insert(icon);
+        }
+        self.touch_regions.extend(other.touch_regions);
+        for (action, slot) in other.action_val.slots() {
+            let Some(threshold) = slot.threshold else { continue };
+            self.action_val.slot_mut(action).threshold.get_or_insert(threshold);
+        }
+        self.inverted.extend(other.inverted);
+        self.inverted_binds.extend(other.inverted_binds);
+        self.absolute_axes.extend(other.absolute_axes);
+        for (bind, processors) in other.processors {
+            self.processors.entry(bind).or_insert(processors);
+        }
+        for (from, to) in other.remaps {
+            self.remaps.entry(from).or_insert(to);
+        }
+        for (action, (cooldown, _)) in other.cooldowns {
+            self.cooldowns.entry(action).or_insert((cooldown, None));
+        }
+        // group numbers are moved past this maps groups so they dont get mixed up
+        let next_group = self.exclusive.values().map(|&(group, _)| group + 1).max().unwrap_or(0);
+        for (action, (group, priority)) in other.exclusive {
+            self.exclusive.entry(action).or_insert((group + next_group, priority));
+        }
+        for (action, axis) in other.axes_2d {
+            self.axes_2d.entry(action).or_insert(axis);
+        }
+        for (action, expr) in other.composites {
+            if self.composites.iter().any(|(a, _)| *a == action) { continue }
+            self.composites.push((action, expr));
+        }
+        for (layer, binds) in other.layers {
+            let Some(i) = self.layers.iter().position(|(l, _)| *l == layer) else {
+                self.layers.push((layer, binds));
+                continue
+            };
+            for (code, actions) in binds {
+                let existing = self.layers[i].1.map_mut().entry(code).or_default();
+                for action in actions {
+                    if !existing.contains(&action) { existing.push(action) }
+                }
+            }
+        }
+        #[cfg(feature = "gamepad")]
+        {
+            for (id, uuid) in other.gamepad_uuids { self.gamepad_uuids.entry(id).or_insert(uuid); }
+            self.pending_gamepad_binds.extend(other.pending_gamepad_binds);
+            for (id, binds) in other.gamepad_binds {
+                self.gamepad_binds.entry(id).or_insert(binds);
+            }
+        }
+        self.bind_cache_gen = None;
+        conflicts
+    }
     /// Gets a mutable vector of what actions input_code is bound to
+    #[deprecated(note = "use `bind`, `bind_many` and `unbind`, which cant create duplicate binds")]
     pub fn mut_bind(&mut self, input_code: InputCode) -> &mut Vec<F> {
-        let has_val = self.binds.contains_key(&input_code);
-        (if has_val { self.binds.get_mut(&input_code) } else {
-            self.binds.insert(input_code, vec![]);
-            self.binds.get_mut(&input_code)
-        }).unwrap()
+        self.binds.map_mut().entry(input_code).or_default()
+    }
+    /// Binds action to an input code. Binding it again does nothing
+    /// ```
+    /// # use winit_input_map::*;
+    /// # use winit::keyboard::KeyCode;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Jump }
+    /// let mut input = InputMap::default();
+    /// input.bind(KeyCode::Space, Action::Jump);
+    /// input.bind(KeyCode::Space, Action::Jump);
+    /// assert!(input.is_bound(KeyCode::Space, Action::Jump));
+    /// assert_eq!(input.binds[&KeyCode::Space.into()].len(), 1);
+    /// ```
+    pub fn bind(&mut self, input_code: impl Into<InputCode>, action: F) {
+        let input_code = input_code.into();
+        let actions = self.binds.map_mut().entry(input_code).or_default();
+        if !actions.contains(&action) { actions.push(action) }
+    }
+    /// Binds each action to its input codes, taking them the same way as `new`
+    pub fn bind_many(&mut self, binds: &[(F, Vec<InputCode>)]) {
+        for (action, codes) in binds {
+            for &code in codes { self.bind(code, action.clone()) }
+        }
+    }
+    /// Removes the bind from an input code to action, if there is one
+    pub fn unbind(&mut self, input_code: impl Into<InputCode>, action: F) {
+        let input_code = input_code.into();
+        let Some(actions) = self.binds.map_mut().get_mut(&input_code) else { return };
+        actions.retain(|a| *a != action);
+        if actions.is_empty() {
+            self.binds.map_mut().remove(&input_code);
+        }
+    }
+    /// Checks if an input code is bound to action
+    pub fn is_bound(&self, input_code: impl Into<InputCode>, action: F) -> bool {
+        self.binds.get(&input_code.into()).is_some_and(|actions| actions.contains(&action))
+    }
+    /// Sets how `a` and `b` are resolved when both are being pressed, such as left and right
+    /// movement. Defaults to `Rollover::Neutral` where both are kept and cancel out through `axis`.
+    /// ```
+    /// # use winit_input_map::*;
+    /// # use winit::keyboard::KeyCode;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Left, Right }
+    /// # use Action::*;
+    /// let mut input = input_map!(
+    ///     (Left,  KeyCode::KeyA),
+    ///     (Right, KeyCode::KeyD)
+    /// );
+    /// // pressing D while holding A will release Left until D is let go
+    /// input.set_rollover(Left, Right, Rollover::LastInputPriority);
+    /// ```
+    pub fn set_rollover(&mut self, a: F, b: F, policy: Rollover) {
+        for (action, other) in [(a.clone(), b.clone()), (b.clone(), a.clone())] {
+            if policy == Rollover::Neutral { self.rollover.remove(&action); }
+            else { self.rollover.insert(action, (other, policy)); }
+        }
+        for action in [a, b] {
+            if let Some(held) = self.action_val.slot_mut(&action).overridden.take() {
+                self.set_action_val(action, held);
+            }
+        }
+    }
+    /// Gives action its own thresholds instead of `press_sensitivity`. It becomes pressed once its
+    /// value reaches `press` and stays pressed until it drops below `release`, which stops analog
+    /// triggers from flickering when held near the threshold. `release` is capped at `press`.
+    /// ```
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Shoot }
+    /// let mut input = input_map!((Action::Shoot, GamepadButton::RightTrigger2));
+    /// input.set_threshold(Action::Shoot, 0.6, 0.45);
+    /// ```
+    pub fn set_threshold(&mut self, action: F, press: f32, release: f32) {
+        let pressing = self.is_pressing(&action);
+        let slot = self.action_val.slot_mut(&action);
+        slot.threshold = Some((press, release.min(press)));
+        slot.past_threshold = pressing;
+    }
+    /// Makes action use `press_sensitivity` again
+    pub fn remove_threshold(&mut self, action: F) {
+        let slot = self.action_val.slot_mut(&action);
+        (slot.threshold, slot.past_threshold) = (None, false);
+    }
+    /// Sets if a `DeviceInput::DeviceAxis` reports a position, such as a throttle, rather than
+    /// how much it moved. Absolute axes keep their last value instead of going back to 0 each
+    /// loop, so they can hold actions. Axes are relative by default
+    pub fn set_device_axis_absolute(&mut self, axis: AxisId, absolute: bool) {
+        if absolute { self.absolute_axes.insert(axis); }
+        else { self.absolute_axes.remove(&axis); }
+    }
+    /// Makes `source` drive two actions. `tap` is pressed for one loop when `source` is released
+    /// before `threshold` and `hold` is pressed once `source` has been held for `threshold`
+    /// until its released. Holds are checked in `init` so they'll be seen the loop after.
+    /// ```
+    /// # use winit_input_map::*;
+    /// # use winit::keyboard::KeyCode;
+    /// # use std::time::Duration;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Shift, Dodge, Sprint }
+    /// # use Action::*;
+    /// let mut input = input_map!((Shift, KeyCode::ShiftLeft));
+    /// input.set_tap_hold(Shift, Dodge, Sprint, Duration::from_millis(200));
+    /// ```
+    pub fn set_tap_hold(&mut self, source: F, tap: F, hold: F, threshold: Duration) {
+        self.tap_hold.insert(source, TapHold { tap, hold, threshold });
+    }
+    /// Stops `source` from driving its tap and hold actions
+    pub fn remove_tap_hold(&mut self, source: F) {
+        self.tap_hold.remove(&source);
+    }
+    /// Inverts every axis action is bound to, so it takes its value from the opposite direction.
+    /// Inverting both actions of an axis is the usual "Invert Y axis" setting. Binds that arent to
+    /// an axis are unaffected.
+    /// ```
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { LookUp, LookDown }
+    /// # use Action::*;
+    /// let mut input = input_map!(
+    ///     (LookUp,   InputCode::MOUSE_MOVE_Y_NEG),
+    ///     (LookDown, InputCode::MOUSE_MOVE_Y_POS)
+    /// );
+    /// let invert_y = true;
+    /// input.set_inverted(LookUp,   invert_y);
+    /// input.set_inverted(LookDown, invert_y);
+    /// ```
+    pub fn set_inverted(&mut self, action: F, inverted: bool) {
+        if inverted { self.inverted.insert(action); } else { self.inverted.remove(&action); }
+    }
+    /// Checks if action is inverted, see `set_inverted`
+    pub fn inverted(&self, action: F) -> bool {
+        self.inverted.contains(&action)
+    }
+    /// Inverts a single bind to an axis, so `action` takes its value from the opposite direction
+    /// of `input_code`. If the action is inverted as well the two cancel out
+    pub fn set_bind_inverted(&mut self, input_code: InputCode, action: F, inverted: bool) {
+        if inverted { self.inverted_binds.insert((input_code, action)); }
+        else { self.inverted_binds.remove(&(input_code, action)); }
+    }
+    /// Makes `from` act as if it were `to` before binds are looked up, so low level layout changes
+    /// dont need every bind to be changed. Remaps go one way, so swapping two inputs needs two.
+    /// Remapping an input code for any device also remaps it for specific devices.
+    /// ```
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Confirm, Back }
+    /// let mut input = input_map!(
+    ///     (Action::Confirm, GamepadButton::South),
+    ///     (Action::Back,    GamepadButton::East)
+    /// );
+    /// // "Swap A/B" setting for Nintendo style confirm
+    /// input.remap(GamepadButton::South, GamepadButton::East);
+    /// input.remap(GamepadButton::East,  GamepadButton::South);
+    /// ```
+    pub fn remap(&mut self, from: impl Into<InputCode>, to: impl Into<InputCode>) {
+        self.remaps.insert(from.into(), to.into());
+    }
+    /// Stops `from` being remapped, see `remap`
+    pub fn remove_remap(&mut self, from: impl Into<InputCode>) {
+        self.remaps.remove(&from.into());
+    }
+    /// Sets the processors that run in order on the value `input_code` gives `action`, replacing any
+    /// from before. For mouse movement and scrolling they run on each movement. An empty list
+    /// removes them.
+    /// ```
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Accelerate }
+    /// let trigger = InputCode::from(GamepadButton::RightTrigger2);
+    /// let mut input = input_map!((Action::Accelerate, trigger));
+    /// input.set_processors(trigger, Action::Accelerate, vec![
+    ///     Processor::Deadzone(0.1),
+    ///     Processor::Curve(2.0)
+    /// ]);
+    /// ```
+    pub fn set_processors(&mut self, input_code: InputCode, action: F, processors: Vec<Processor>) {
+        if processors.is_empty() { self.processors.remove(&(input_code, action)); }
+        else { self.processors.insert((input_code, action), processors); }
+    }
+    /// Sets if action is used as a toggle, where each press flips `toggled`. Useful for
+    /// accessibility options like toggle crouch. Turning it off clears the toggle.
+    pub fn set_toggle(&mut self, action: F, toggle: bool) {
+        if toggle { self.toggles.entry(action).or_insert(false); }
+        else { self.toggles.remove(&action); }
+    }
+    /// If action is a toggle, sets it on or off. Does nothing if it isnt a toggle.
+    pub fn set_toggled(&mut self, action: F, toggled: bool) {
+        if let Some(t) = self.toggles.get_mut(&action) { *t = toggled }
+    }
+    /// Checks if a toggle action is toggled on. If action isnt a toggle this is the same as
+    /// `pressing` so the same check works for both hold and toggle settings.
+    pub fn toggled(&self, action: F) -> bool {
+        self.toggles.get(&action).copied().unwrap_or_else(|| self.pressing(action))
+    }
+    /// Adds a sequence of actions that have to be pressed in order, like a fighting game combo.
+    /// Each step has the maximum time allowed since the previous step, the first steps time is
+    /// ignored. Pressing an action from the sequence out of order or too late starts it over,
+    /// while actions that arent in it are ignored. Empty sequences are ignored.
+    /// ```
+    /// # use winit_input_map::*;
+    /// # use winit::keyboard::KeyCode;
+    /// # use std::time::Duration;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Down, Forward, Punch }
+    /// # use Action::*;
+    /// let mut input = input_map!(
+    ///     (Down,    KeyCode::KeyS),
+    ///     (Forward, KeyCode::KeyD),
+    ///     (Punch,   KeyCode::KeyJ)
+    /// );
+    /// let step = Duration::from_millis(250);
+    /// let fireball = input.add_sequence(&[(Down, step), (Forward, step), (Punch, step)]);
+    /// // every loop
+    /// if input.sequence_completed(fireball) { /* ... */ }
+    /// ```
+    pub fn add_sequence(&mut self, steps: &[(F, Duration)]) -> SequenceId {
+        let id = SequenceId(self.sequences.len());
+        if steps.is_empty() { return SequenceId(usize::MAX) }
+        self.sequences.push(Sequence {
+            steps: steps.to_vec(), progress: 0, last_step: Instant::now(), completed: false
+        });
+        id
+    }
+    /// Checks if the last step of a sequence was just pressed in time
+    pub fn sequence_completed(&self, id: SequenceId) -> bool {
+        self.sequences.get(id.0).is_some_and(|s| s.completed)
+    }
+    /// Sets the cursor icon to show while action is being pressed, such as `CursorIcon::Grabbing`
+    /// while a pan action is held. Use `None` to remove it. See `apply_cursor`
+    pub fn set_cursor_icon(&mut self, action: F, icon: Option<CursorIcon>) {
+        if let Some(icon) = icon { self.cursor_icons.insert(action, icon); }
+        else { self.cursor_icons.remove(&action); }
+    }
+    /// Returns the cursor icon of the most recently pressed action with one, or `default_cursor`
+    pub fn cursor_icon(&self) -> CursorIcon {
+        self.cursor_icons.iter()
+            .filter(|(action, _)| self.is_pressing(action))
+            .max_by_key(|(action, _)| self.timing.get(action).map(|t| t.pressed_at))
+            .map_or(self.default_cursor, |(_, &icon)| icon)
+    }
+    /// Sets the windows cursor to `cursor_icon` if it has changed since it was last applied, and
+    /// releases the cursor if `reset` let go of it. Call once a loop after updating the input map.
+    pub fn apply_cursor(&mut self, window: &Window) {
+        if std::mem::take(&mut self.release_capture) {
+            window.set_cursor_grab(CursorGrabMode::None).ok();
+            window.set_cursor_visible(true);
+        }
+        let icon = self.cursor_icon();
+        if self.applied_cursor != Some(icon) {
+            window.set_cursor(icon);
+            self.applied_cursor = Some(icon);
+        }
     }
     /// Updates the input map using a winit event. Make sure to call `input.init()` when your done with
     /// the input this loop.
-    /// ```
-    /// use winit::{event::*, window::Window, event_loop::EventLoop};
-    /// use winit_input_map::InputMap;
+    /// ```no_run
+    /// # #![allow(deprecated)]
+    /// use winit::{event::*, event_loop::EventLoop};
+    /// use winit_input_map::*;
     ///
-    /// let mut event_loop = EventLoop::new().unwrap();
+    /// let event_loop = EventLoop::new().unwrap();
     /// event_loop.set_control_flow(winit::event_loop::ControlFlow::Poll);
-    /// let _window = Window::new(&event_loop).unwrap();
     ///
     /// let mut input = input_map!();
     ///
-    /// event_loop.run(|event, target|{
+    /// event_loop.run(|event, target| {
     ///     input.update_with_winit(&event);
-    ///     match &event{
+    ///     match &event {
     ///         Event::WindowEvent { event: WindowEvent::CloseRequested, .. } => target.exit(),
     ///         Event::AboutToWait => input.init(),
     ///         _ => ()
     ///     }
-    /// });
+    /// }).unwrap();
     /// ```
     #[deprecated = "use `update_with_window_event` and `update_with_device_event`"]
     pub fn update_with_winit(&mut self, event: &Event<()>) {
         match event {
             Event::WindowEvent { event, .. } => self.update_with_window_event(event),
-            Event::DeviceEvent { event, .. } => self.update_with_device_event(event),
+            Event::DeviceEvent { event, device_id } =>
+                self.update_with_device_event_from(*device_id, event),
             _ => ()
         }
     }
+    /// Same as `update_with_device_event` but knows which device the event is from, so it can be
+    /// filtered by `device_filter`, trigger binds to that specific device and be shown by
+    /// `recent_device`
+    pub fn update_with_device_event_from(&mut self, device_id: DeviceId, event: &DeviceEvent) {
+        if !self.device_filter.allows(device_id) { return }
+        self.event_device = Some(device_id);
+        self.update_with_device_event(event);
+        self.event_device = None;
+    }
     pub fn update_with_device_event(&mut self, event: &DeviceEvent) {
+        self.sync_deprecated_settings();
+        self.event_source = match event {
+            DeviceEvent::Key(_) => Some(InputSource::Keyboard),
+            DeviceEvent::Motion { .. } => Some(InputSource::DeviceAxis),
+            _ => Some(InputSource::Mouse)
+        };
+        self.handle_device_event(event);
+        self.event_source = None;
+    }
+    fn handle_device_event(&mut self, event: &DeviceEvent) {
         match event {
             DeviceEvent::MouseMotion { delta } => {
-                let x = delta.0 as f32 * self.mouse_scale;
-                let y = delta.1 as f32 * self.mouse_scale;
-                self.modify_val(DeviceInput::MouseMoveX(AxisSign::Pos).into(), |v| *v += x.max(0.0));
-                self.modify_val(DeviceInput::MouseMoveX(AxisSign::Neg).into(), |v| *v += (-x).max(0.0));
-                self.modify_val(DeviceInput::MouseMoveY(AxisSign::Pos).into(), |v| *v += y.max(0.0));
-                self.modify_val(DeviceInput::MouseMoveY(AxisSign::Neg).into(), |v| *v += (-y).max(0.0));
+                self.mouse_delta.0 += delta.0;
+                self.mouse_delta.1 += delta.1;
+                if self.pointer_captured { return }
+                self.add_mouse_motion(delta.0 as f32, delta.1 as f32);
+            },
+            // while focused these come through as window events instead
+            DeviceEvent::Key(key) if self.background_input && !self.focused => {
+                self.update_val(key.physical_key.into(), key.state.is_pressed() as u8 as f32)
+            },
+            DeviceEvent::Button { button, state } if self.background_input && !self.focused => {
+                let button = DeviceInput::RawButton(*button).into();
+                self.update_val(button, state.is_pressed() as u8 as f32)
+            },
+            DeviceEvent::Motion { .. } if self.pointer_captured => (),
+            DeviceEvent::Motion { axis, value } => {
+                let value = *value as f32;
+                let pos = DeviceInput::DeviceAxis(*axis, AxisSign::Pos);
+                let neg = DeviceInput::DeviceAxis(*axis, AxisSign::Neg);
+                if self.absolute_axes.contains(axis) {
+                    self.set_device_axis(pos.into(), value.max(0.0));
+                    self.set_device_axis(neg.into(), (-value).max(0.0));
+                } else {
+                    self.add_device_axis(pos.into(), value.max(0.0));
+                    self.add_device_axis(neg.into(), (-value).max(0.0));
+                }
             },
+            DeviceEvent::MouseWheel { .. } if self.pointer_captured => (),
             DeviceEvent::MouseWheel { delta } => {
                 let (x, y) = match delta {
-                    MouseScrollDelta::LineDelta(x, y) => (*x, *y),
-                    MouseScrollDelta::PixelDelta(PhysicalPosition { x, y }) => (*x as f32, *y as f32)
+                    MouseScrollDelta::LineDelta(x, y) => (*x as f64, *y as f64),
+                    MouseScrollDelta::PixelDelta(PhysicalPosition { x, y }) => (*x, *y)
                 };
-                let (x, y) = (x * self.mouse_scale, y * self.mouse_scale);
-                self.modify_val(DeviceInput::MouseScroll(AxisSign::Pos ).into(), |v| *v += y.max(0.0));
-                self.modify_val(DeviceInput::MouseScroll(AxisSign::Neg ).into(), |v| *v += (-y).max(0.0));
-                self.modify_val(DeviceInput::MouseScrollX(AxisSign::Pos).into(), |v| *v += x.max(0.0));
-                self.modify_val(DeviceInput::MouseScrollX(AxisSign::Neg).into(), |v| *v += (-x).max(0.0));
+                let (step, scale) = match delta {
+                    MouseScrollDelta::LineDelta(..) => (1.0, self.settings.scroll_scale),
+                    MouseScrollDelta::PixelDelta(_) => (
+                        self.settings.scroll_step_pixels.max(f32::EPSILON) as f64,
+                        self.settings.scroll_pixel_scale
+                    )
+                };
+                self.scroll_delta.0 += x / step;
+                self.scroll_delta.1 += y / step;
+                self.add_scroll_steps(x / step, y / step);
+                let (x, y) = (x as f32 * scale, y as f32 * scale);
+                self.add_loop_axis(4, y.max(0.0));
+                self.add_loop_axis(5, (-y).max(0.0));
+                self.add_loop_axis(6, x.max(0.0));
+                self.add_loop_axis(7, (-x).max(0.0));
             },
              _ => (),
         }
     }
+    /// adds scrolling towards whole scroll steps, pressing the scroll step codes for each one
+    fn add_scroll_steps(&mut self, x: f64, y: f64) {
+        let partial = &mut self.scroll_step_partial;
+        // scrolling back the other way starts from nothing rather than undoing the last scroll
+        if x * partial.0 < 0.0 { partial.0 = 0.0 }
+        if y * partial.1 < 0.0 { partial.1 = 0.0 }
+        partial.0 += x;
+        partial.1 += y;
+        let steps = (partial.0.trunc(), partial.1.trunc());
+        partial.0 -= steps.0;
+        partial.1 -= steps.1;
+        if steps == (0.0, 0.0) { return }
+        self.scroll_steps.0 += steps.0 as i32;
+        self.scroll_steps.1 += steps.1 as i32;
+        let (x, y) = self.scroll_steps;
+        self.update_val(DeviceInput::ScrollStep( AxisSign::Pos).into(), y.max(0)    as f32);
+        self.update_val(DeviceInput::ScrollStep( AxisSign::Neg).into(), (-y).max(0) as f32);
+        self.update_val(DeviceInput::ScrollStepX(AxisSign::Pos).into(), x.max(0)    as f32);
+        self.update_val(DeviceInput::ScrollStepX(AxisSign::Neg).into(), (-x).max(0) as f32);
+    }
+    /// smooths raw mouse movement with the `MouseFilter`s then scales it and adds it to the mouse
+    /// move axes
+    fn add_mouse_motion(&mut self, x: f32, y: f32) {
+        let dt = self.last_mouse_filter.elapsed();
+        self.last_mouse_filter = Instant::now();
+        let [filter_x, filter_y] = &mut self.mouse_filters;
+        let x = self.settings.mouse_filter_x.apply(x, dt, filter_x) * self.settings.mouse_scale_x;
+        let y = self.settings.mouse_filter_y.apply(y, dt, filter_y) * self.settings.mouse_scale_y;
+        self.add_loop_axis(0, x.max(0.0));
+        self.add_loop_axis(1, (-x).max(0.0));
+        self.add_loop_axis(2, y.max(0.0));
+        self.add_loop_axis(3, (-y).max(0.0));
+    }
+    /// adds to one of `LOOP_AXES`. the half of an axis that didnt move is skipped, which is most
+    /// of them as mice send lots of small movements
+    fn add_loop_axis(&mut self, axis: usize, amount: f32) {
+        if amount == 0.0 { return }
+        self.loop_axes_moved[axis] = true;
+        let code = LOOP_AXES[axis];
+        if self.loop_axis_unbound(axis) {
+            let val = self.code_val(code) + amount;
+            return self.set_code_val(code, val)
+        }
+        self.modify_val(code, |v| *v += amount);
+    }
+    /// sets one of `LOOP_AXES`, only updating its raw value if nothing is bound to it
+    fn set_loop_axis(&mut self, axis: usize, val: f32) {
+        if self.loop_axis_unbound(axis) { return self.set_code_val(LOOP_AXES[axis], val) }
+        self.update_val(LOOP_AXES[axis], val)
+    }
+    /// adds to a device axis code, only updating its raw value if no device axis is bound
+    fn add_device_axis(&mut self, code: InputCode, amount: f32) {
+        if amount == 0.0 { return }
+        if !self.device_axes_moved.contains(&code) { self.device_axes_moved.push(code) }
+        if self.device_axes_unbound() {
+            let val = self.code_val(code) + amount;
+            return self.set_code_val(code, val)
+        }
+        self.modify_val(code, |v| *v += amount);
+    }
+    /// sets an absolute device axis code, only updating its raw value if no device axis is bound
+    fn set_device_axis(&mut self, code: InputCode, val: f32) {
+        if self.device_axes_unbound() { return self.set_code_val(code, val) }
+        self.update_val(code, val)
+    }
+    /// checks if nothing uses device axis codes, so looking up their binds can be skipped
+    fn device_axes_unbound(&mut self) -> bool {
+        self.refresh_bind_cache();
+        !self.device_axes_bound && self.remaps.is_empty() && self.axes_2d.is_empty()
+    }
+    /// checks if nothing uses one of `LOOP_AXES`, so looking up its binds can be skipped
+    fn loop_axis_unbound(&mut self, axis: usize) -> bool {
+        self.refresh_bind_cache();
+        !self.loop_axes_bound[axis] && self.remaps.is_empty() && self.axes_2d.is_empty()
+    }
+    /// works out which of `LOOP_AXES` are bound, including on layers, with modifiers, to a
+    /// specific device or inverted to the opposite sign, and if any device axis is bound. Does
+    /// nothing if the binds havent changed
+    fn refresh_bind_cache(&mut self) {
+        if self.bind_cache_gen == Some(self.binds.generation()) { return }
+        self.bind_cache_gen = Some(self.binds.generation());
+        self.loop_axes_bound = [false; 17];
+        self.device_axes_bound = false;
+        let layers = self.layers.iter().flat_map(|(_, binds)| binds.keys());
+        for code in self.binds.keys().chain(layers) {
+            let code = code.without_modifiers().set_any();
+            self.device_axes_bound |= code.kind() == InputKind::DeviceAxis;
+            let opposite = code.axis_sign().map(|sign| code.set_axis_sign(sign.opposite()));
+            for (bound, axis) in self.loop_axes_bound.iter_mut().zip(LOOP_AXES) {
+                *bound |= axis == code || Some(axis) == opposite;
+            }
+        }
+    }
     pub fn update_with_window_event(&mut self, event: &WindowEvent) {
+        self.sync_deprecated_settings();
+        let device = match event {
+            WindowEvent::CursorMoved { device_id, .. } | WindowEvent::MouseInput { device_id, .. }
+            | WindowEvent::KeyboardInput { device_id, .. } | WindowEvent::PinchGesture { device_id, .. }
+            | WindowEvent::RotationGesture { device_id, .. } | WindowEvent::PanGesture { device_id, .. }
+            | WindowEvent::DoubleTapGesture { device_id }
+            | WindowEvent::Touch(winit::event::Touch { device_id, .. }) => Some(*device_id),
+            _ => None
+        };
+        if device.is_some_and(|id| !self.device_filter.allows(id)) { return }
+        self.event_source = match event {
+            WindowEvent::KeyboardInput { .. } | WindowEvent::Ime(_) => Some(InputSource::Keyboard),
+            WindowEvent::Touch(_) => Some(InputSource::Touch),
+            _ if device.is_some() => Some(InputSource::Mouse),
+            _ => None
+        };
+        self.event_device = device;
+        self.handle_window_event(event);
+        (self.event_device, self.event_source) = (None, None);
+    }
+    fn handle_window_event(&mut self, event: &WindowEvent) {
         match event {
+            // a grabbed cursor is pinned in place so its position is meaningless
+            WindowEvent::CursorMoved { .. } if self.mouse_captured => (),
             WindowEvent::CursorMoved { position, .. } => self.update_mouse(*position),
+            WindowEvent::CursorEntered { .. } => self.cursor_inside = true,
+            WindowEvent::CursorLeft { .. } => self.cursor_inside = false,
+            WindowEvent::HoveredFile(path) => self.hovered_files.push(path.clone()),
+            WindowEvent::HoveredFileCancelled => self.hovered_files.clear(),
+            WindowEvent::DroppedFile(path) => {
+                // a drop ends the hover, with an event for each file
+                self.hovered_files.retain(|p| p != path);
+                self.dropped_files.push(path.clone())
+            },
             WindowEvent::MouseInput { state, button, .. } => self.update_buttons(state, *button),
+            WindowEvent::Touch(touch) => self.update_touch(touch),
+            WindowEvent::PinchGesture { .. } | WindowEvent::RotationGesture { .. }
+            | WindowEvent::PanGesture { .. } | WindowEvent::DoubleTapGesture { .. }
+                if self.pointer_captured => (),
+            WindowEvent::PinchGesture { delta, .. } if !delta.is_nan() => {
+                let delta = *delta as f32;
+                self.add_loop_axis(8, delta.max(0.0));
+                self.add_loop_axis(9, (-delta).max(0.0));
+            },
+            WindowEvent::RotationGesture { delta, .. } => {
+                self.add_loop_axis(10, delta.max(0.0));
+                self.add_loop_axis(11, (-delta).max(0.0));
+            },
+            WindowEvent::PanGesture { delta, .. } => {
+                let (x, y) = (delta.x * self.settings.pan_scale, delta.y * self.settings.pan_scale);
+                self.add_loop_axis(12, x.max(0.0));
+                self.add_loop_axis(13, (-x).max(0.0));
+                self.add_loop_axis(14, y.max(0.0));
+                self.add_loop_axis(15, (-y).max(0.0));
+            },
+            WindowEvent::DoubleTapGesture { .. } => {
+                // always goes through the binds so it can be picked in `recently_pressed`
+                self.loop_axes_moved[DOUBLE_TAP_AXIS] = true;
+                self.update_val(LOOP_AXES[DOUBLE_TAP_AXIS], 1.0)
+            },
             WindowEvent::KeyboardInput { event, .. } => self.update_keys(event),
+            WindowEvent::Ime(ime) => self.update_ime(ime),
+            WindowEvent::ModifiersChanged(modifiers) => self.modifiers = modifiers.state(),
+            WindowEvent::Resized(size) => self.window_size = *size,
+            WindowEvent::Focused(focused) => self.focused = *focused,
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => self.scale_factor = *scale_factor,
             _ => ()
         }
     }
     #[cfg(feature = "gamepad")]
     pub fn update_with_gilrs(&mut self, gilrs: &mut gilrs::Gilrs) {
-        while let Some(ev) = gilrs.next_event() {
+        self.sync_deprecated_settings();
+        while let Some(mut ev) = gilrs.next_event() {
+            let uuid = gilrs.gamepad(ev.id).uuid();
+            self.gamepad_uuids.entry(ev.id).or_insert(uuid);
+            #[cfg(feature = "prompts")]
+            self.gamepad_families.entry(ev.id)
+                .or_insert_with(|| crate::GamepadFamily::of(&gilrs.gamepad(ev.id)));
+            match ev.event {
+                gilrs::EventType::ButtonChanged(
+                    b @ (GamepadButton::LeftTrigger2 | GamepadButton::RightTrigger2), val, code
+                ) => ev.event = gilrs::EventType::ButtonChanged(b, self.offset_trigger(uuid, b, val), code),
+                gilrs::EventType::AxisChanged(axis, val, code) => {
+                    self.center_sticks(gilrs.gamepad(ev.id));
+                    ev.event = gilrs::EventType::AxisChanged(axis, self.calibrate(ev.id, axis, val), code)
+                },
+                gilrs::EventType::Connected => {
+                    if self.learn_trigger_rest { self.take_trigger_rest(gilrs.gamepad(ev.id), false) }
+                    let ids = self.disconnected_gamepads.get_mut(&uuid);
+                    if let Some(old) = ids.filter(|i| !i.is_empty()).map(|i| i.remove(0)) {
+                        self.reassign_gamepad(old, ev.id);
+                    }
+                    self.apply_pending_gamepad_binds();
+                },
+                gilrs::EventType::Disconnected => {
+                    self.disconnected_gamepads.entry(uuid).or_default().push(ev.id);
+                    self.code_vals.retain(|code, _| !matches!(code,
+                        InputCode::Gamepad { id: SpecifyGamepad::Id(id), .. } if *id == ev.id
+                    ));
+                },
+                _ => ()
+            }
             self.update_gamepad(ev);
         }
+        self.update_flick_stick();
     }
-    /// Makes the input map ready to recieve new events.
-    pub fn init(&mut self) {
-        self.update_val(DeviceInput::MouseMoveX(  AxisSign::Pos).into(), 0.0);
-        self.update_val(DeviceInput::MouseMoveX(  AxisSign::Neg).into(), 0.0);
-        self.update_val(DeviceInput::MouseMoveY(  AxisSign::Pos).into(), 0.0);
-        self.update_val(DeviceInput::MouseMoveY(  AxisSign::Neg).into(), 0.0);
-        self.update_val(DeviceInput::MouseScroll( AxisSign::Pos).into(), 0.0);
-        self.update_val(DeviceInput::MouseScroll( AxisSign::Neg).into(), 0.0);
-        self.update_val(DeviceInput::MouseScrollX(AxisSign::Pos).into(), 0.0);
-        self.update_val(DeviceInput::MouseScrollX(AxisSign::Neg).into(), 0.0);
-        self.action_val.iter_mut().for_each(|(_, i)|
-            *i = (i.0, false, false)
-        );
-        self.recently_pressed = None;
-        self.text_typed = None;
-    }
-    fn update_mouse(&mut self, position: PhysicalPosition<f64>) {
-        self.mouse_pos = v(position.x as f32, position.y as f32);
+    #[cfg(feature = "gamepad")]
+    fn update_flick_stick(&mut self) {
+        let Some((stick, _, _)) = &self.flick_stick else { return };
+        let x = self.val(&stick.right) - self.val(&stick.left);
+        let y = self.val(&stick.up) - self.val(&stick.down);
+        if let Some((stick, last, delta)) = &mut self.flick_stick {
+            *delta = stick.process(x, y, last);
+        }
     }
-    fn update_keys(&mut self, event: &KeyEvent) {
-        let input_code = event.physical_key.into();
-
-        if let (Some(string), Some(new)) = (&mut self.text_typed, &event.text) {
-            string.push_str(new);
-        } else { self.text_typed = event.text.as_ref().map(|i| i.to_string()) }
-
-        self.update_val(input_code, event.state.is_pressed() as u8 as f32);
+    /// Turns on flick stick controls for a stick, or turns them off with `None`. The stick is
+    /// read after gamepad events in `update_with_gilrs`, see `flick_delta`
+    /// ```
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Right, Left, Up, Down }
+    /// use Action::*;
+    /// let mut input = input_map!(
+    ///     (Right, GamepadInput::Axis(gilrs::Axis::RightStickX, AxisSign::Pos)),
+    ///     (Left,  GamepadInput::Axis(gilrs::Axis::RightStickX, AxisSign::Neg)),
+    ///     (Up,    GamepadInput::Axis(gilrs::Axis::RightStickY, AxisSign::Pos)),
+    ///     (Down,  GamepadInput::Axis(gilrs::Axis::RightStickY, AxisSign::Neg))
+    /// );
+    /// input.set_flick_stick(Some(FlickStick::new(Right, Left, Up, Down)));
+    /// ```
+    #[cfg(feature = "gamepad")]
+    pub fn set_flick_stick(&mut self, stick: Option<crate::FlickStick<F>>) {
+        self.flick_stick = stick.map(|stick| (stick, None, 0.0));
     }
-    fn update_buttons(&mut self, state: &ElementState, button: MouseButton) {
-        let input_code = button.into();
-        self.update_val(input_code, state.is_pressed() as u8 as f32);
+    /// How many degrees the camera should turn around this loop from the flick stick. Positive
+    /// is clockwise, the same as moving the mouse right
+    #[cfg(feature = "gamepad")]
+    pub fn flick_delta(&self) -> f32 {
+        self.flick_stick.as_ref().map_or(0.0, |(_, _, delta)| *delta)
     }
-    /// updates provided input code
-    fn update_val(&mut self, input_code: InputCode, val: f32) {
-        let pressed = val >= self.press_sensitivity;
-        if pressed { self.recently_pressed = Some(input_code) }
-        if let Some(binds) = self.binds.get(&input_code) {
-            for &action in binds {
-                let jpressed = pressed && !self.pressing(action);
-                let released = !pressed && self.pressing(action);
-                self.action_val.insert(action, (val, jpressed, released));
+    /// Takes the center of the sticks when calibration starts or for auto centering
+    #[cfg(feature = "gamepad")]
+    fn center_sticks(&mut self, gamepad: gilrs::Gamepad) {
+        let id = gamepad.id();
+        let calibrating = self.calibrating.get(&id) == Some(&false);
+        let auto_center = self.auto_center && self.auto_centered.insert(id);
+        if !(calibrating || auto_center) { return }
+        if calibrating { self.calibrating.insert(id, true); }
+        for axis in STICK_AXES {
+            let val = gamepad.value(axis);
+            if calibrating {
+                self.axis_calibration.insert((id, axis), AxisCalibration { center: val, min: val, max: val });
+            } else if val.abs() < 0.2 {
+                self.axis_calibration.entry((id, axis))
+                    .or_insert(AxisCalibration { center: val, ..Default::default() });
             }
         }
     }
-    fn modify_val<FN: Fn(&mut f32)>(&mut self, input_code: InputCode, f: FN) {
-        if let Some(binds) = self.binds.get(&input_code) {
-            for &action in binds {
-                let mut val = self.action_val.get(&action)
-                    .unwrap_or(&(0.0, false, false)).0;
-                f(&mut val);
-                let pressed = val >= self.press_sensitivity;
-                if pressed { self.recently_pressed = Some(input_code) }
-                
-                let jpressed = pressed && !self.pressing(action);
-                let released = !pressed && self.pressing(action);
-                self.action_val.insert(action, (val, jpressed, released));
-            }
+    /// Applies the calibration of an axis, or widens its range while calibrating
+    #[cfg(feature = "gamepad")]
+    fn calibrate(&mut self, id: gilrs::GamepadId, axis: GamepadAxis, val: f32) -> f32 {
+        let Some(calibration) = self.axis_calibration.get_mut(&(id, axis)) else { return val };
+        if self.calibrating.contains_key(&id) {
+            calibration.min = calibration.min.min(val);
+            calibration.max = calibration.max.max(val);
+            return val
         }
+        calibration.apply(val)
     }
+    /// Starts calibrating a gamepads sticks, taking where they and the triggers are now as their
+    /// center and `trigger_rest`. The sticks and triggers should be left alone when this is
+    /// called, then the sticks rotated all the way around before calling `finish_calibration`.
+    /// Values are left uncalibrated until then. Needs the input map to own its `Gilrs`, see
+    /// `with_gamepads`, otherwise use `start_calibration_with`.
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # let mut input = input_map!().with_gamepads();
+    /// # let id = input.gamepads()[0].id;
+    /// input.start_calibration(id);
+    /// // after the player has rotated the sticks
+    /// input.finish_calibration(id);
+    /// ```
     #[cfg(feature = "gamepad")]
-    fn update_gamepad(&mut self, event: gilrs::Event) {
-        let gilrs::Event { id, event, .. } = event;
-
-        use gilrs::ev::EventType;
-        match event {
-            EventType::ButtonPressed(b, _) => {
-                let a: GamepadInput = b.into();
-                self.update_val(a.with_id(id), 1.0);
-                self.update_val(b.into(),      1.0);
-            },
-            EventType::ButtonReleased(b, _) => {
-                let a: GamepadInput = b.into();
-                self.update_val(a.with_id(id), 0.0);
-                self.update_val(b.into(),      0.0);
-            },
-            EventType::ButtonChanged(b, v, _) => {
-                let a: GamepadInput = b.into();
-                self.update_val(b.into(),                    v);
-                self.update_val(a.with_id(id), v);
-            },
-            EventType::AxisChanged(b, v, _) => {
-                let dir_pos = v.max(0.0);
-                let dir_neg = (-v).max(0.0);
-                let input_pos = InputCode::gamepad_axis_pos(b);
-                let input_neg = InputCode::gamepad_axis_neg(b);
-
-                self.update_val(input_pos,                    dir_pos);
-                self.update_val(input_neg,                    dir_neg);
-                self.update_val(input_pos.set_gamepad_id(id), dir_pos);
-                self.update_val(input_neg.set_gamepad_id(id), dir_neg);
+    pub fn start_calibration(&mut self, id: gilrs::GamepadId) {
+        if let Some(gilrs) = self.gilrs.take() {
+            self.start_calibration_with(&gilrs, id);
+            self.gilrs = Some(gilrs);
+        }
+    }
+    /// Like `start_calibration` but for a `Gilrs` passed to `update_with_gilrs`
+    #[cfg(feature = "gamepad")]
+    pub fn start_calibration_with(&mut self, gilrs: &gilrs::Gilrs, id: gilrs::GamepadId) {
+        let Some(gamepad) = gilrs.connected_gamepad(id) else { return };
+        self.calibrating.insert(id, false);
+        self.take_trigger_rest(gamepad, true);
+        self.center_sticks(gamepad);
+    }
+    /// Finishes calibrating a gamepad, see `start_calibration`. Sticks that werent moved far keep
+    /// their new center but go back to the default range.
+    #[cfg(feature = "gamepad")]
+    pub fn finish_calibration(&mut self, id: gilrs::GamepadId) {
+        if self.calibrating.remove(&id) != Some(true) { return }
+        for axis in STICK_AXES {
+            let Some(calibration) = self.axis_calibration.get_mut(&(id, axis)) else { continue };
+            if calibration.max - calibration.center < 0.3 || calibration.center - calibration.min < 0.3 {
+                *calibration = AxisCalibration { center: calibration.center, ..Default::default() };
             }
-            _ => ()
         }
     }
-    /// Checks if action is being pressed currently. same as `input.action_val(action) >=
-    /// input.press_sensitivity`
-    pub fn pressing(&self, action: F) -> bool {
-        self.action_val(action) >= self.press_sensitivity
+    /// Whether a gamepad is being calibrated, see `start_calibration`
+    #[cfg(feature = "gamepad")]
+    pub fn calibrating(&self, id: gilrs::GamepadId) -> bool {
+        self.calibrating.contains_key(&id)
     }
-    /// Checks how much action is being pressed. May be higher than 1 in the case of scroll wheels
-    /// and mouse movement.
-    pub fn action_val(&self, action: F) -> f32 {
-        if let Some(&(v, _, _)) = self.action_val.get(&action) { v } else {  0.0  }
+    /// Takes where the triggers of a gamepad are as their rest value, keeping any it already has
+    /// unless `replace`
+    #[cfg(feature = "gamepad")]
+    fn take_trigger_rest(&mut self, gamepad: gilrs::Gamepad, replace: bool) {
+        for button in [GamepadButton::LeftTrigger2, GamepadButton::RightTrigger2] {
+            let Some(data) = gamepad.button_data(button) else { continue };
+            let key = (gamepad.uuid(), button);
+            if replace || !self.trigger_rest.contains_key(&key) {
+                self.trigger_rest.insert(key, data.value());
+            }
+        }
     }
-    /// checks if action was just pressed
-    pub fn pressed(&self, action: F) -> bool {
-        if let Some(&(_, v, _)) = self.action_val.get(&action) { v } else { false }
+    /// Offsets a trigger value by its rest value
+    #[cfg(feature = "gamepad")]
+    fn offset_trigger(&mut self, uuid: [u8; 16], button: GamepadButton, val: f32) -> f32 {
+        let rest = self.trigger_rest.get(&(uuid, button)).copied().unwrap_or(0.0);
+        if rest >= 1.0 { return val }
+        ((val - rest) / (1.0 - rest)).max(0.0)
     }
-    /// checks if action was just released
-    pub fn released(&self, action: F) -> bool {
-        if let Some(&(_, _, v)) = self.action_val.get(&action) { v } else { false }
+    /// Updates the input map using the `Gilrs` instance it owns. See `with_gamepads`
+    #[cfg(feature = "gamepad")]
+    pub fn update_gamepads(&mut self) {
+        if let Some(mut gilrs) = self.gilrs.take() {
+            self.update_with_gilrs(&mut gilrs);
+            self.gilrs = Some(gilrs);
+        }
     }
-    /// Returns f32 based on how much pos and neg are pressed. may return values higher than 1.0 in
-    /// the case of mouse movement and scrolling. usefull for movement controls. for 2d values see
-    /// `[dir]` and `[dir_max_len_1]`
-    /// ```
-    /// let move_dir = input.axis(Neg, Pos);
+    /// Rumble and trigger effects for a gamepad. Needs the input map to own its `Gilrs`, see
+    /// `with_gamepads`, otherwise every effect does nothing.
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # use std::time::Duration;
+    /// # let mut input = input_map!().with_gamepads();
+    /// # let id = input.gamepads()[0].id;
+    /// input.haptics(id).rumble(1.0, 0.5, Duration::from_millis(200));
     /// ```
-    /// same as `input.action_val(pos) - input.action_val(neg)`
-    pub fn axis(&self, pos: F, neg: F) -> f32 {
-        self.action_val(pos) - self.action_val(neg)
+    #[cfg(feature = "haptics")]
+    pub fn haptics(&mut self, id: gilrs::GamepadId) -> crate::Haptics<'_> {
+        crate::Haptics { gilrs: self.gilrs.as_mut(), effects: &mut self.rumble, id }
     }
-    /// Returns a vector based off of x and y axis. For movement controls see `dir_max_len_1`
+    /// The family of a gamepad, known once it has sent an event
+    #[cfg(feature = "prompts")]
+    pub fn gamepad_family(&self, id: gilrs::GamepadId) -> crate::GamepadFamily {
+        self.gamepad_families.get(&id).copied().unwrap_or_default()
+    }
+    /// Updates the gyro and accelerometer of a gamepad. gilrs doesnt read motion sensors, so they
+    /// need to come from somewhere else such as SDL or reading the gamepad over HID. Call whenever
+    /// theres a new reading.
+    #[cfg(feature = "gamepad")]
+    pub fn update_motion(&mut self, id: gilrs::GamepadId, motion: Motion) {
+        self.motion.insert(id, motion);
+        self.update_gyro_aim(motion);
+        self.event_source = Some(InputSource::Gamepad(id));
+        let axes = [MotionAxis::X, MotionAxis::Y, MotionAxis::Z];
+        for (i, axis) in axes.into_iter().enumerate() {
+            let gyro = motion.gyro[i] * self.settings.gyro_scale;
+            for (input, val) in [
+                (GamepadInput::Gyro(axis, AxisSign::Pos), gyro),
+                (GamepadInput::Gyro(axis, AxisSign::Neg), -gyro),
+                (GamepadInput::Accel(axis, AxisSign::Pos), motion.accel[i]),
+                (GamepadInput::Accel(axis, AxisSign::Neg), -motion.accel[i])
+            ] {
+                self.update_val(input.into(), val.max(0.0));
+                self.update_val(input.with_id(id), val.max(0.0));
+            }
+        }
+        self.event_source = None;
+    }
+    /// Updates the touchpad of a gamepad. Like motion sensors gilrs doesnt read touchpads, so
+    /// this needs to come from somewhere else. Call whenever the touchpad changes.
+    #[cfg(feature = "gamepad")]
+    pub fn update_touchpad(&mut self, id: gilrs::GamepadId, touchpad: Touchpad) {
+        self.touchpads.insert(id, touchpad);
+        self.event_source = Some(InputSource::Gamepad(id));
+        let [x, y] = touchpad.position.unwrap_or_default();
+        let bool_val = |b| if b { 1.0 } else { 0.0 };
+        for (input, val) in [
+            (GamepadInput::TouchpadX(AxisSign::Pos), x),
+            (GamepadInput::TouchpadX(AxisSign::Neg), -x),
+            (GamepadInput::TouchpadY(AxisSign::Pos), y),
+            (GamepadInput::TouchpadY(AxisSign::Neg), -y),
+            (GamepadInput::TouchpadTouch, bool_val(touchpad.position.is_some())),
+            (GamepadInput::TouchpadClick, bool_val(touchpad.clicked))
+        ] {
+            self.update_val(input.into(), val.max(0.0));
+            self.update_val(input.with_id(id), val.max(0.0));
+        }
+        self.event_source = None;
+    }
+    /// The last touchpad state of a gamepad, see `update_touchpad`. Useful for moving a cursor
+    #[cfg(feature = "gamepad")]
+    pub fn touchpad(&self, id: gilrs::GamepadId) -> Option<Touchpad> {
+        self.touchpads.get(&id).copied()
+    }
+    #[cfg(feature = "gamepad")]
+    fn update_gyro_aim(&mut self, motion: Motion) {
+        let (gravity, last, _) = &mut self.gyro_state;
+        let now = Instant::now();
+        // readings far apart are likely from after a pause so are ignored
+        let dt = last.replace(now).map_or(0.0, |last| (now - last).as_secs_f32()).min(0.1);
+        for (g, a) in gravity.iter_mut().zip(motion.accel) { *g += (a - *g) * (dt * 4.0).min(1.0) }
+        let Some(aim) = &self.gyro_aim else { return };
+        if aim.enable_while.as_ref().is_some_and(|action| !self.is_pressing(action)) { return }
+        let (yaw, pitch) = aim.process(motion.gyro, self.gyro_state.0);
+        let delta = &mut self.gyro_state.2;
+        delta.0 += yaw * dt;
+        delta.1 += pitch * dt;
+    }
+    /// Turns on aiming with the gyro, or turns it off with `None`. Motion readings need to be fed
+    /// in with `update_motion`, see `gyro_delta`
+    /// ```
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Aim }
+    /// let mut input = input_map!((Action::Aim, GamepadButton::LeftTrigger2));
+    /// input.set_gyro_aim(Some(GyroAim { enable_while: Some(Action::Aim), ..Default::default() }));
+    /// ```
+    #[cfg(feature = "gamepad")]
+    pub fn set_gyro_aim(&mut self, aim: Option<crate::GyroAim<F>>) {
+        self.gyro_aim = aim;
+    }
+    /// How many degrees the camera should turn this loop from aiming with the gyro, as yaw and
+    /// pitch. Add it to the cameras rotation like mouse movement
+    #[cfg(feature = "gamepad")]
+    pub fn gyro_delta(&self) -> Vec2 {
+        let (yaw, pitch) = self.gyro_state.2;
+        v(yaw, pitch)
+    }
+    /// The last motion sensor reading of a gamepad, see `update_motion`
+    #[cfg(feature = "gamepad")]
+    pub fn motion(&self, id: gilrs::GamepadId) -> Option<Motion> {
+        self.motion.get(&id).copied()
+    }
+    /// Returns the connected gamepads, for listing them in settings or warning about low battery.
+    /// Empty unless the input map owns its `Gilrs`, see `with_gamepads`
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # let input = input_map!().with_gamepads();
+    /// for gamepad in input.gamepads() {
+    ///     if gamepad.battery().is_some_and(|b| b < 15) {
+    ///         println!("{} is low on battery", gamepad.name)
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "gamepad")]
+    pub fn gamepads(&self) -> Vec<GamepadInfo> {
+        self.gilrs.iter().flat_map(|gilrs| gilrs.gamepads().map(|(_, g)| g.into())).collect()
+    }
+    /// Gives the gamepad `id` its own binds, used instead of `binds` for its events. Useful when
+    /// gamepads with different layouts are connected at once, such as a flight stick and an Xbox
+    /// controller, or to give each player their own binds. Two gamepads of the same model keep
+    /// their own binds, and when one reconnects with a new id its binds move over to it. See
+    /// `saved_gamepad_binds` to keep them between runs.
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Fire }
+    /// let mut input = input_map!((Action::Fire, GamepadButton::RightTrigger2)).with_gamepads();
+    /// let stick = input.gamepads()[0].id;
+    /// input.set_gamepad_binds(stick, &[(Action::Fire, vec![GamepadButton::South.into()])]);
+    /// ```
+    #[cfg(feature = "gamepad")]
+    pub fn set_gamepad_binds(&mut self, id: gilrs::GamepadId, binds: &[(F, Vec<InputCode>)]) {
+        let mut result = Binds::default();
+        for (action, codes) in binds {
+            for code in codes { result.map_mut().entry(*code).or_default().push(action.clone()) }
+        }
+        self.gamepad_binds.insert(id, result);
+    }
+    /// Makes the gamepad `id` use `binds` again
+    #[cfg(feature = "gamepad")]
+    pub fn remove_gamepad_binds(&mut self, id: gilrs::GamepadId) {
+        self.gamepad_binds.remove(&id);
+    }
+    /// The binds given to each gamepad by `set_gamepad_binds`, for saving along with the rest of
+    /// the settings. Only gamepad inputs are kept, as other inputs never reach gamepad binds
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Fire }
+    /// let mut input = input_map!((Action::Fire, GamepadButton::RightTrigger2)).with_gamepads();
+    /// let saved = input.saved_gamepad_binds();
+    /// // next run, the binds come back once each gamepad connects
+    /// let mut input = input_map!((Action::Fire, GamepadButton::RightTrigger2)).with_gamepads();
+    /// input.restore_gamepad_binds(saved);
+    /// ```
+    #[cfg(feature = "gamepad")]
+    pub fn saved_gamepad_binds(&self) -> Vec<SavedGamepadBinds<F>> {
+        let mut saved = Vec::new();
+        for (id, binds) in &self.gamepad_binds {
+            let Some(uuid) = self.gamepad_uuids.get(id).copied() else { continue };
+            let mut actions: Vec<(F, Vec<GamepadInput>)> = Vec::new();
+            for (code, bound) in binds.iter() {
+                let InputCode::Gamepad { input, .. } = code else { continue };
+                for action in bound {
+                    match actions.iter_mut().find(|(a, _)| a == action) {
+                        Some((_, inputs)) => inputs.push(*input),
+                        None => actions.push((action.clone(), vec![*input]))
+                    }
+                }
+            }
+            let index = self.gamepad_index(*id, uuid);
+            saved.push(SavedGamepadBinds { uuid, index, binds: actions });
+        }
+        saved
+    }
+    /// Gives back binds from `saved_gamepad_binds`. Gamepads that are connected get them now and
+    /// the rest get them when they connect
+    #[cfg(feature = "gamepad")]
+    pub fn restore_gamepad_binds(&mut self, saved: Vec<SavedGamepadBinds<F>>) {
+        self.pending_gamepad_binds = saved;
+        if let Some(gilrs) = &self.gilrs {
+            for (id, gamepad) in gilrs.gamepads() {
+                self.gamepad_uuids.entry(id).or_insert(gamepad.uuid());
+            }
+        }
+        self.apply_pending_gamepad_binds();
+    }
+    /// where the gamepad is among the gamepads with the same uuid, in the order of their ids
+    #[cfg(feature = "gamepad")]
+    fn gamepad_index(&self, id: gilrs::GamepadId, uuid: [u8; 16]) -> usize {
+        let before = |other: &gilrs::GamepadId| usize::from(*other) < usize::from(id);
+        self.gamepad_uuids.iter().filter(|&(other, u)| *u == uuid && before(other)).count()
+    }
+    /// gives binds from `restore_gamepad_binds` to the gamepads they were saved from
+    #[cfg(feature = "gamepad")]
+    fn apply_pending_gamepad_binds(&mut self) {
+        if self.pending_gamepad_binds.is_empty() { return }
+        let ids: Vec<_> = self.gamepad_uuids.iter().map(|(id, uuid)| (*id, *uuid)).collect();
+        for (id, uuid) in ids {
+            if self.gamepad_binds.contains_key(&id) { continue }
+            let index = self.gamepad_index(id, uuid);
+            let pending = &mut self.pending_gamepad_binds;
+            let Some(i) = pending.iter().position(|s| s.uuid == uuid && s.index == index) else {
+                continue
+            };
+            let saved = pending.remove(i);
+            let binds: Vec<(F, Vec<InputCode>)> = saved.binds.into_iter()
+                .map(|(action, inputs)| (action, inputs.into_iter().map(Into::into).collect()))
+                .collect();
+            self.set_gamepad_binds(id, &binds);
+        }
+    }
+    /// Moves every bind, calibration and the rest kept for the gamepad `from` over to the gamepad
+    /// `to`.
+    /// This is done automatically by `update_with_gilrs` when a disconnected gamepad, such as a
+    /// wireless one that went to sleep, comes back with a new id.
+    #[cfg(feature = "gamepad")]
+    pub fn reassign_gamepad(&mut self, from: gilrs::GamepadId, to: gilrs::GamepadId) {
+        if from == to { return }
+        #[cfg(feature = "haptics")]
+        self.rumble.remove(&from);
+        self.remap_gamepads(&HashMap::from([(from, to)]), true);
+    }
+    /// moves everything kept for each gamepad over to its new id at once, so gamepads that swap
+    /// ids dont get mixed up. Gamepads left out of `ids` keep theirs if `keep_unmapped`, otherwise
+    /// its dropped
+    #[cfg(feature = "gamepad")]
+    fn remap_gamepads(
+        &mut self, ids: &HashMap<gilrs::GamepadId, gilrs::GamepadId>, keep_unmapped: bool
+    ) {
+        fn rekey<K: Hash + Eq, V>(map: &mut HashMap<K, V>, key: impl Fn(K) -> Option<K>) {
+            let old = std::mem::take(map);
+            *map = old.into_iter().filter_map(|(k, v)| Some((key(k)?, v))).collect();
+        }
+        if ids.is_empty() && keep_unmapped { return }
+        let new_id = |id| ids.get(&id).copied().or(keep_unmapped.then_some(id));
+        let moved: Vec<(InputCode, Vec<F>)> = self.binds.keys()
+            .filter(|code| matches!(code, InputCode::Gamepad { id: SpecifyGamepad::Id(id), .. }
+                if ids.contains_key(id) || !keep_unmapped
+            ))
+            .copied().collect::<Vec<_>>().into_iter()
+            .map(|code| (code, self.binds.map_mut().remove(&code).unwrap_or_default()))
+            .collect();
+        for (code, actions) in moved {
+            let InputCode::Gamepad { id: SpecifyGamepad::Id(id), .. } = code else { continue };
+            let Some(id) = new_id(id) else { continue };
+            for action in actions { self.bind(code.set_gamepad_id(id), action) }
+        }
+        rekey(&mut self.axis_calibration, |(id, axis)| Some((new_id(id)?, axis)));
+        rekey(&mut self.calibrating, new_id);
+        rekey(&mut self.motion, new_id);
+        rekey(&mut self.touchpads, new_id);
+        rekey(&mut self.gamepad_binds, new_id);
+        rekey(&mut self.gamepad_uuids, new_id);
+        #[cfg(feature = "prompts")]
+        rekey(&mut self.gamepad_families, new_id);
+        let auto_centered = std::mem::take(&mut self.auto_centered);
+        self.auto_centered = auto_centered.into_iter().filter_map(new_id).collect();
+    }
+    /// Makes the input map ready to recieve new events.
+    pub fn init(&mut self) {
+        self.sync_deprecated_settings();
+        if self.history_len > 0 {
+            if self.history.len() >= self.history_len {
+                self.history.drain(..=self.history.len() - self.history_len);
+            }
+            self.history.push_back(HistoryFrame { time: Instant::now(), state: self.snapshot() });
+        } else { self.history.clear() }
+        // only axes that moved need setting back to 0, saving lookups on frames without movement
+        let moved = std::mem::take(&mut self.loop_axes_moved);
+        let half_life = self.settings.scroll_half_life.as_secs_f32();
+        let dt = self.last_init.elapsed().as_secs_f32();
+        for (i, (code, moved)) in LOOP_AXES.into_iter().zip(moved).enumerate() {
+            let val = if SCROLL_AXES.contains(&i) && half_life > 0.0 {
+                let s = i - SCROLL_AXES.start;
+                let fresh = (self.code_val(code) - self.scroll_carried[s]).max(0.0);
+                let decay = 0.5f32.powf(dt / half_life);
+                let speed = &mut self.scroll_speed[s];
+                // the next loop gets what the speed covered over the loop that just ended, so
+                // the slowing down adds up the same however long loops take
+                let carried = *speed * (1.0 - decay) * half_life / std::f32::consts::LN_2;
+                *speed = *speed * decay + fresh * std::f32::consts::LN_2 / half_life;
+                if *speed < 0.001 { *speed = 0.0 }
+                let carried = if carried < 0.001 { 0.0 } else { carried };
+                self.scroll_carried[s] = carried;
+                // scrolling that hasnt died down yet still needs to slow down next loop
+                self.loop_axes_moved[i] = carried != 0.0 || *speed != 0.0;
+                if !moved && carried == 0.0 { continue }
+                carried
+            } else if moved { 0.0 } else { continue };
+            self.set_loop_axis(i, val)
+        }
+        if half_life == 0.0 { self.scroll_speed = [0.0; 4]; self.scroll_carried = [0.0; 4]; }
+        for code in std::mem::take(&mut self.device_axes_moved) {
+            if self.device_axes_unbound() { self.set_code_val(code, 0.0) }
+            else { self.update_val(code, 0.0) }
+        }
+        // smoothed movement thats still catching up keeps coming after the mouse stops
+        self.add_mouse_motion(0.0, 0.0);
+        if std::mem::take(&mut self.scroll_steps) != (0, 0) {
+            self.update_val(DeviceInput::ScrollStep( AxisSign::Pos).into(), 0.0);
+            self.update_val(DeviceInput::ScrollStep( AxisSign::Neg).into(), 0.0);
+            self.update_val(DeviceInput::ScrollStepX(AxisSign::Pos).into(), 0.0);
+            self.update_val(DeviceInput::ScrollStepX(AxisSign::Neg).into(), 0.0);
+        }
+        self.action_val.iter_mut().for_each(|(_, i)|
+            *i = (i.0, false, false)
+        );
+        self.code_vals.iter_mut().for_each(|(_, i)|
+            *i = (i.0, false, false)
+        );
+        self.changed.clear();
+        self.prev_init = std::mem::replace(&mut self.last_init, Instant::now());
+        self.sequences.iter_mut().for_each(|s| s.completed = false);
+        for tap in std::mem::take(&mut self.tapped) { self.set_action_val(tap, 0.0) }
+        let holds: Vec<F> = self.tap_hold.iter().filter(|(source, th)|
+            !self.is_pressing(&th.hold) && self.is_pressing(source)
+                && self.timing.get(source).is_some_and(|t| t.pressed_at.elapsed() >= th.threshold)
+        ).map(|(_, th)| th.hold.clone()).collect();
+        for hold in holds { self.set_action_val(hold, 1.0) }
+        self.mouse_delta = (0.0, 0.0);
+        self.scroll_delta = (0.0, 0.0);
+        #[cfg(feature = "gamepad")]
+        { self.gyro_state.2 = (0.0, 0.0); }
+        self.drags.retain(|_, d| !d.ended);
+        self.touches.retain(|_, t| !matches!(t.phase, TouchPhase::Ended | TouchPhase::Cancelled));
+        self.recently_pressed = None;
+        self.canceled.clear();
+        self.pressed_this_loop.clear();
+        self.text_typed = None;
+        self.text_events.clear();
+        self.dropped_files.clear();
+    }
+    /// Releases every action and clears all held state such as input values, toggles, drags and
+    /// sequence progress. Binds and settings are kept. Released actions will show as `released`
+    /// this loop. The cursor is let go of if its captured, which takes effect on the next
+    /// `apply_cursor`
+    pub fn reset(&mut self) {
+        self.release_capture |= std::mem::take(&mut self.mouse_captured);
+        // nothing overridden should come back as the actions overriding it are let go
+        for (_, slot) in self.action_val.slots_mut() {
+            (slot.overridden, slot.consumed) = (None, false);
+        }
+        let held: Vec<F> = self.action_val.iter().filter(|(_, v)| v.0 != 0.0)
+            .map(|(action, _)| action.clone()).collect();
+        for action in held { self.force_release(action) }
+        self.code_vals.clear();
+        self.loop_axes_moved = [false; LOOP_AXES.len()];
+        self.device_axes_moved.clear();
+        self.timing.clear();
+        self.held_layered.clear();
+        self.dispatched.clear();
+        self.axis_2d_vals.clear();
+        self.mouse_delta = (0.0, 0.0);
+        self.scroll_delta = (0.0, 0.0);
+        self.scroll_step_partial = (0.0, 0.0);
+        self.scroll_steps = (0, 0);
+        self.scroll_speed = [0.0; 4];
+        self.scroll_carried = [0.0; 4];
+        self.held_modified.clear();
+        self.held_logical.clear();
+        self.wildcards.clear();
+        self.drags.clear();
+        self.touches.clear();
+        self.pen = None;
+        self.primary_touch = None;
+        self.joysticks.iter_mut().for_each(|(_, touch)| *touch = None);
+        self.tapped.clear();
+        self.toggles.values_mut().for_each(|t| *t = false);
+        self.sequences.iter_mut().for_each(|s| s.progress = 0);
+        self.scrubs.clear();
+    }
+    fn update_mouse(&mut self, position: PhysicalPosition<f64>) {
+        self.mouse_pos = v(position.x as f32, position.y as f32);
+        self.cursor_pos = position;
+        self.cursor_inside = true;
+        for drag in self.drags.values_mut() {
+            let (x, y) = (position.x - drag.start.x, position.y - drag.start.y);
+            drag.started |= (x*x + y*y).sqrt() as f32 >= self.drag_threshold;
+        }
+    }
+    fn update_keys(&mut self, event: &KeyEvent) {
+        let input_code = event.physical_key.into();
+
+        if let (Some(string), Some(new)) = (&mut self.text_typed, &event.text) {
+            string.push_str(new);
+        } else if let Some(new) = &event.text { self.text_typed = Some(new.to_string()) }
+
+        use KeyCode::*;
+        match (event.state.is_pressed(), event.physical_key, &event.text) {
+            (true, PhysicalKey::Code(code @ (
+                Backspace | Delete | Enter | NumpadEnter | Tab | Escape
+                | ArrowLeft | ArrowRight | ArrowUp | ArrowDown | Home | End
+            )), _) => self.text_events.push_back(TextEvent::Key(code)),
+            (true, _, Some(new)) => match self.text_events.back_mut() {
+                Some(TextEvent::Text(string)) => string.push_str(new),
+                _ => self.text_events.push_back(TextEvent::Text(new.to_string()))
+            },
+            _ => ()
+        }
+
+        let typing = self.text_input_mode
+            && event.text.as_ref().is_some_and(|t| t.chars().any(|c| !c.is_control()));
+        // the panic action still works so it can get the app out of a stuck state
+        let suppressed = event.state.is_pressed() && (typing || self.keyboard_captured)
+            && !self.is_panic_key(event);
+        // repeats are handled by `pressed_or_repeat` so they work the same for every input
+        if !event.repeat && !suppressed {
+            let pressed = event.state.is_pressed();
+            // modifiers change what keys type, such as shift turning 1 into !, so names are only
+            // learnt without them
+            let unmodified = self.modifiers.is_empty();
+            if let (true, true, Key::Character(c)) = (pressed, unmodified, &event.logical_key) {
+                self.key_names.insert(event.physical_key, c.to_uppercase());
+            }
+            let logical = if pressed {
+                let logical = DeviceInput::logical(&event.logical_key).map(InputCode::from);
+                if let Some(code) = logical { self.held_logical.insert(event.physical_key, code); }
+                logical
+            } else { self.held_logical.remove(&event.physical_key) };
+            if let Some(code) = logical { self.update_val(code, pressed as u8 as f32) }
+            self.update_val(input_code, pressed as u8 as f32);
+        }
+    }
+    /// checks if a key is bound to `panic_action` by its position or meaning, with or without
+    /// modifiers
+    fn is_panic_key(&self, event: &KeyEvent) -> bool {
+        let Some(panic) = &self.panic_action else { return false };
+        let physical = event.physical_key.into();
+        let logical = DeviceInput::logical(&event.logical_key).map(InputCode::from);
+        self.binds.iter().any(|(code, actions)| {
+            let code = code.without_modifiers().set_any();
+            (code == physical || Some(code) == logical) && actions.contains(panic)
+        })
+    }
+    fn update_ime(&mut self, ime: &Ime) {
+        match ime {
+            Ime::Preedit(text, _) if text.is_empty() => self.preedit = None,
+            Ime::Preedit(text, cursor) => self.preedit = Some((text.clone(), *cursor)),
+            Ime::Commit(text) => {
+                self.preedit = None;
+                self.text_typed.get_or_insert_with(String::new).push_str(text);
+                match self.text_events.back_mut() {
+                    Some(TextEvent::Text(string)) => string.push_str(text),
+                    _ => self.text_events.push_back(TextEvent::Text(text.clone()))
+                }
+            },
+            Ime::Disabled => self.preedit = None,
+            Ime::Enabled => ()
+        }
+    }
+    fn update_buttons(&mut self, state: &ElementState, button: MouseButton) {
+        let input_code = button.into();
+        if state.is_pressed() {
+            let drag = DragState { start: self.cursor_pos, started: false, ended: false };
+            self.drags.insert(button, drag);
+        } else if let Some(drag) = self.drags.get_mut(&button) {
+            if drag.started { drag.ended = true } else { self.drags.remove(&button); }
+        }
+        if !(self.pointer_captured && state.is_pressed()) {
+            self.update_val(input_code, state.is_pressed() as u8 as f32);
+        }
+    }
+    fn update_touch(&mut self, touch: &winit::event::Touch) {
+        if touch.phase == TouchPhase::Started { self.last_source = Some(InputSource::Touch) }
+        let force = touch.force.map(|f| f.normalized() as f32);
+        let (captured, start, started_at) = self.touches.get(&touch.id).map_or(
+            (self.pointer_captured, touch.location, Instant::now()),
+            |t| (t.captured, t.start, t.started_at)
+        );
+        let state = TouchState {
+            captured, start, pos: touch.location, phase: touch.phase, force, started_at
+        };
+        self.touches.insert(touch.id, state);
+        if let Some(pressure) = force { self.update_pen(touch, pressure) }
+        match touch.phase {
+            TouchPhase::Started if self.primary_touch.is_none() => self.primary_touch = Some(touch.id),
+            // the oldest touch still held takes over so the pointer doesnt jump to the mouse
+            TouchPhase::Ended | TouchPhase::Cancelled if self.primary_touch == Some(touch.id) => {
+                self.primary_touch = self.touches.iter()
+                    .filter(|(_, t)| !matches!(t.phase, TouchPhase::Ended | TouchPhase::Cancelled))
+                    .min_by_key(|(_, t)| t.started_at)
+                    .map(|(&id, _)| id)
+            },
+            _ => ()
+        }
+
+        let mut pressed: HashMap<F, bool> = HashMap::new();
+        for (region, action) in &self.touch_regions {
+            let held = self.touches.values().any(|t|
+                !t.captured && !matches!(t.phase, TouchPhase::Ended | TouchPhase::Cancelled)
+                    && region.contains(self.normalize(t.start))
+            );
+            *pressed.entry(action.clone()).or_default() |= held;
+        }
+        for (action, held) in pressed {
+            if held != self.is_pressing(&action) { self.set_action_val(action, held as u8 as f32) }
+        }
+        self.update_joysticks(touch);
+    }
+    fn update_pen(&mut self, touch: &winit::event::Touch, pressure: f32) {
+        let tilt = match touch.force {
+            Some(Force::Calibrated { altitude_angle: Some(angle), .. }) =>
+                Some(1.0 - (angle / std::f64::consts::FRAC_PI_2) as f32),
+            _ => None
+        };
+        self.pen = match touch.phase {
+            TouchPhase::Ended | TouchPhase::Cancelled => None,
+            _ => Some((pressure, tilt))
+        };
+        let (pressure, tilt) = self.pen.unwrap_or_default();
+        self.update_val(DeviceInput::PenPressure.into(), pressure);
+        self.update_val(DeviceInput::PenTilt.into(), tilt.unwrap_or_default());
+    }
+    fn update_joysticks(&mut self, touch: &winit::event::Touch) {
+        let pos = touch.location;
+        let normalized = self.normalize(pos);
+        let started = touch.phase == TouchPhase::Started && !self.pointer_captured;
+        for i in 0..self.joysticks.len() {
+            let joystick = self.joysticks[i].0;
+            let controlled = &mut self.joysticks[i].1;
+            let center = match *controlled {
+                None if started && joystick.region.contains(normalized) => {
+                    *controlled = Some((touch.id, pos));
+                    pos
+                },
+                Some((id, center)) if id == touch.id => center,
+                _ => continue
+            };
+            let (mut x, mut y) = ((pos.x - center.x) as f32, (pos.y - center.y) as f32);
+            if matches!(touch.phase, TouchPhase::Ended | TouchPhase::Cancelled) {
+                *controlled = None;
+                (x, y) = (0.0, 0.0);
+            }
+            let radius = joystick.radius.max(f32::EPSILON);
+            let len = (x * x + y * y).sqrt();
+            if len > radius {
+                if let (true, Some((_, center))) = (joystick.recenter, controlled) {
+                    let past = (len - radius) / len;
+                    center.x += (x * past) as f64;
+                    center.y += (y * past) as f64;
+                }
+                (x, y) = (x / len * radius, y / len * radius);
+            }
+            // screen y is towards the bottom but the joystick codes are up
+            let (x, y) = (x / radius, -y / radius);
+            let id = JoystickId(i);
+            self.update_val(DeviceInput::JoystickX(id, AxisSign::Pos).into(), x.max(0.0));
+            self.update_val(DeviceInput::JoystickX(id, AxisSign::Neg).into(), (-x).max(0.0));
+            self.update_val(DeviceInput::JoystickY(id, AxisSign::Pos).into(), y.max(0.0));
+            self.update_val(DeviceInput::JoystickY(id, AxisSign::Neg).into(), (-y).max(0.0));
+        }
+    }
+    /// a position relative to the window size
+    fn normalize(&self, pos: PhysicalPosition<f64>) -> (f32, f32) {
+        let (w, h) = (self.window_size.width.max(1) as f32, self.window_size.height.max(1) as f32);
+        (pos.x as f32 / w, pos.y as f32 / h)
+    }
+    /// updates provided input code
+    fn update_val(&mut self, input_code: InputCode, val: f32) {
+        self.set_code_val(input_code, val);
+        let input_code = self.remapped(input_code);
+        self.update_axes_2d(input_code, val);
+        let pressed = val >= self.settings.press_sensitivity;
+        // inputs pressed on a layer stay on it until release, even if the layer is let go first
+        let layer = self.held_layered.get(&input_code).copied()
+            .or_else(|| self.active_layer(input_code).filter(|_| pressed));
+        match layer {
+            Some(layer) if pressed => { self.held_layered.insert(input_code, layer); },
+            _ => { self.held_layered.remove(&input_code); }
+        }
+        match layer {
+            Some(layer) => self.with_layer(layer, |s| s.update_bound_val(input_code, val)),
+            None => self.update_bound_val(input_code, val)
+        }
+    }
+    /// the first layer being held that binds `input_code`
+    fn active_layer(&self, input_code: InputCode) -> Option<usize> {
+        self.layers.iter().position(|(layer, binds)|
+            binds.contains_key(&input_code) && self.code_pressing(*layer)
+        )
+    }
+    /// runs `f` with the binds of a layer in place of `binds`
+    fn with_layer(&mut self, layer: usize, f: impl FnOnce(&mut Self)) {
+        std::mem::swap(&mut self.binds, &mut self.layers[layer].1);
+        f(self);
+        std::mem::swap(&mut self.binds, &mut self.layers[layer].1);
+    }
+    /// updates the actions bound to an already remapped input code
+    fn update_bound_val(&mut self, input_code: InputCode, val: f32) {
+        let pressed = val >= self.settings.press_sensitivity;
+        if pressed { self.record_recent(input_code, val) }
+        if let Some(wildcard) = input_code.wildcard() {
+            let held = self.wildcards.entry(wildcard).or_default();
+            if pressed { held.insert(input_code); } else { held.remove(&input_code); }
+            let wild_val = !held.is_empty() as u8 as f32;
+            self.set_code_val(wildcard, wild_val);
+            self.each_bind(wildcard, |s, code, action| {
+                let val = s.process(code, &action, wild_val);
+                s.set_action_val(action, val)
+            });
+        }
+        if pressed && !self.modifiers.is_empty() {
+            let modified = input_code.with_modifiers(self.modifiers);
+            if modified != input_code && self.binds.contains_key(&modified) {
+                self.held_modified.insert(input_code, modified);
+            }
+        }
+        // the modified bind is used until release, even if the modifiers are let go first
+        let code = match self.held_modified.get(&input_code) {
+            Some(&modified) if !pressed => { self.held_modified.remove(&input_code); modified },
+            Some(&modified) => modified,
+            None => input_code
+        };
+        // binds to the specific device the event came from are updated too
+        for code in std::iter::once(code).chain(self.device_specific(code)) {
+            self.each_bind(code, |s, code, action| {
+                let val = s.process(code, &action, val);
+                s.set_action_val(action, val)
+            });
+        }
+    }
+    /// applies `remaps` to an input code, keeping the device or gamepad its from
+    fn remapped(&self, input_code: InputCode) -> InputCode {
+        if let Some(&to) = self.remaps.get(&input_code) { return to }
+        let Some(&to) = self.remaps.get(&input_code.set_any()) else { return input_code };
+        match input_code {
+            InputCode::Device { id: SpecifyDevice::Id(id), .. }
+            | InputCode::Modified { id: SpecifyDevice::Id(id), .. } => to.set_device_id(id),
+            #[cfg(feature = "gamepad")]
+            InputCode::Gamepad { id: SpecifyGamepad::Id(id), .. } => to.set_gamepad_id(id),
+            _ => to
+        }
+    }
+    /// Updates the raw state of an input code and the device it came from, before remaps and binds
+    fn set_code_val(&mut self, input_code: InputCode, val: f32) {
+        if val >= self.idle_threshold && !self.idle_ignore.contains(&input_code.kind()) {
+            self.last_input = Instant::now();
+        }
+        for code in std::iter::once(input_code).chain(self.device_specific(input_code)) {
+            let pressed = val >= self.settings.press_sensitivity;
+            let state = self.code_vals.entry(code).or_default();
+            let was_pressed = state.0 >= self.settings.press_sensitivity;
+            *state = (val, state.1 || pressed && !was_pressed, state.2 || !pressed && was_pressed);
+        }
+    }
+    /// the input code for the device the event being handled came from, if its known and different
+    fn device_specific(&self, input_code: InputCode) -> Option<InputCode> {
+        self.event_device.map(|id| input_code.set_device_id(id)).filter(|code| *code != input_code)
+    }
+    fn modify_val<FN: Fn(&mut f32)>(&mut self, input_code: InputCode, f: FN) {
+        let mut code_val = self.code_val(input_code);
+        f(&mut code_val);
+        self.set_code_val(input_code, code_val);
+        let input_code = self.remapped(input_code);
+        self.update_axes_2d(input_code, code_val);
+        if let Some(layer) = self.active_layer(input_code) {
+            return self.with_layer(layer, |s| s.modify_bound_val(input_code, f))
+        }
+        self.modify_bound_val(input_code, f)
+    }
+    /// updates the actions bound to an already remapped input code by changing their value
+    fn modify_bound_val<FN: Fn(&mut f32)>(&mut self, input_code: InputCode, f: FN) {
+        for code in std::iter::once(input_code).chain(self.device_specific(input_code)) {
+            self.each_bind(code, |s, code, action| {
+                let old = s.action_val.slot(&action).and_then(|slot| slot.overridden)
+                    .unwrap_or_else(|| s.val(&action));
+                let mut val = old;
+                f(&mut val);
+                // processors apply to the change so accumulated values arent processed twice
+                let val = old + s.process(code, &action, val - old);
+                if val >= s.settings.press_sensitivity { s.record_recent(input_code, val) }
+                s.set_action_val(action, val);
+            });
+        }
+    }
+    /// records the time of a new press and checks if its a double press
+    fn record_press(&mut self, action: F) {
+        let now = Instant::now();
+        let double = self.timing.get(&action).is_some_and(|t|
+            !t.double && now - t.pressed_at <= self.double_press_time
+        );
+        self.timing.insert(action, Timing { pressed_at: now, released_at: None, double });
+    }
+    /// moves sequences forward if action is their next step, and starts them over if its a
+    /// different step or the next step took too long. Actions that arent part of a sequence are
+    /// ignored by it, so holding movement during a combo doesnt break it
+    fn advance_sequences(&mut self, action: &F) {
+        let now = Instant::now();
+        for seq in &mut self.sequences {
+            if !seq.steps.iter().any(|(step, _)| step == action) { continue }
+            let timed_out = seq.progress > 0
+                && now - seq.last_step > seq.steps[seq.progress].1;
+            if timed_out || seq.steps[seq.progress].0 != *action { seq.progress = 0 }
+            if seq.steps[seq.progress].0 == *action {
+                seq.progress += 1;
+                seq.last_step = now;
+            }
+            if seq.progress == seq.steps.len() {
+                seq.progress = 0;
+                seq.completed = true;
+            }
+        }
+    }
+    /// runs the processors of a bind on a value
+    fn process(&self, input_code: InputCode, action: &F, val: f32) -> f32 {
+        self.processors.get(&(input_code, action.clone()))
+            .map_or(val, |processors| processors.iter().fold(val, |val, p| p.apply(val)))
+    }
+    /// records a pressed input code for `recently_pressed`
+    fn record_recent(&mut self, input_code: InputCode, val: f32) {
+        self.recently_pressed = Some(input_code);
+        self.recent_device = self.event_device;
+        if self.event_source.is_some() { self.last_source = self.event_source }
+        self.pressed_this_loop.push((input_code, val));
+    }
+    /// records a press or release for `tick_pressed` and `tick_released`
+    fn tick_edge(&mut self, action: F, pressed: bool, released: bool) {
+        let edges = self.tick_edges.entry(action).or_default();
+        edges.0 |= pressed;
+        edges.1 |= released;
+    }
+    /// runs `f` for every action bound to `input_code` along with the code its bound to. inverted
+    /// binds to the opposite axis sign are run instead of inverted binds to `input_code`
+    fn each_bind(&mut self, input_code: InputCode, mut f: impl FnMut(&mut Self, InputCode, F)) {
+        let opposite = input_code.axis_sign().map(|sign| input_code.set_axis_sign(sign.opposite()));
+        for (code, inverted) in [(Some(input_code), false), (opposite, true)] {
+            let Some(code) = code else { continue };
+            if let Some(binds) = self.binds.map_mut_untracked().get_mut(&code).map(std::mem::take) {
+                for action in &binds {
+                    if self.bind_inverted(code, action) != inverted { continue }
+                    if self.wins_bind(action, &binds) { f(self, code, action.clone()) }
+                    // losers are let go so they arent stuck pressed once the winner takes the input
+                    else if self.is_pressing(action) { self.set_action_val(action.clone(), 0.0) }
+                }
+                self.binds.map_mut_untracked().insert(code, binds);
+            }
+        }
+    }
+    /// sets 2D actions using an already remapped input code to the length of their direction.
+    /// inputs are processed and inverted the same as binds
+    fn update_axes_2d(&mut self, input_code: InputCode, val: f32) {
+        if self.axes_2d.is_empty() { return }
+        let opposite = input_code.axis_sign().map(|sign| input_code.set_axis_sign(sign.opposite()));
+        let mut changed: Vec<(F, InputCode, f32)> = vec![];
+        for (action, axis) in &self.axes_2d {
+            if self.inactive.contains(action) { continue }
+            for (code, inverted) in [(Some(input_code), false), (opposite, true)] {
+                let Some(code) = code else { continue };
+                if !axis.contains(code) || self.bind_inverted(code, action) != inverted { continue }
+                changed.push((action.clone(), code, self.process(code, action, val)));
+            }
+        }
+        for (action, code, val) in changed {
+            self.axis_2d_vals.insert((action.clone(), code), val);
+            let (x, y) = self.axis_2d_dir(&action);
+            let len = (x * x + y * y).sqrt();
+            if self.val(&action) != len { self.set_action_val(action, len) }
+        }
+    }
+    /// the direction of a 2D action from its processed inputs
+    fn axis_2d_dir(&self, action: &F) -> (f32, f32) {
+        if self.inactive.contains(action) { return (0.0, 0.0) }
+        let Some(axis) = self.axes_2d.get(action) else { return (0.0, 0.0) };
+        axis.eval_swizzled(
+            |code| self.axis_2d_vals.get(&(action.clone(), code)).copied().unwrap_or(0.0),
+            |code| self.processors.get(&(code, action.clone())).is_some_and(|processors| {
+                processors.iter().any(|p| matches!(p, Processor::Swizzle))
+            })
+        )
+    }
+    /// updates the composite actions that depend on action
+    fn update_composites(&mut self, action: &F) {
+        if self.composites.is_empty() { return }
+        let changed: Vec<(F, f32)> = self.composites.iter()
+            .filter(|(_, expr)| expr.uses(action))
+            .map(|(composite, expr)| (composite.clone(), expr.eval(&|a| self.val(a))))
+            .collect();
+        for (composite, val) in changed {
+            if self.val(&composite) != val { self.set_action_val(composite, val) }
+        }
+    }
+    /// if action is active and no active action bound to the same input beats it in its group
+    fn wins_bind(&self, action: &F, binds: &[F]) -> bool {
+        if self.inactive.contains(action) { return false }
+        let Some(&(group, priority)) = self.exclusive.get(action) else { return true };
+        !binds.iter().any(|other| !self.inactive.contains(other)
+            && self.exclusive.get(other).is_some_and(|&(g, p)| g == group && p > priority))
+    }
+    /// if a bind to an axis is inverted by its own flag or its actions, but not both
+    fn bind_inverted(&self, input_code: InputCode, action: &F) -> bool {
+        input_code.axis_sign().is_some()
+            && self.inverted_binds.contains(&(input_code, action.clone())) != self.inverted.contains(action)
+    }
+    /// sets the value of an action, updating pressed and released and resolving its rollover
+    fn set_action_val(&mut self, action: F, val: f32) {
+        let slot = self.action_val.slot(&action).copied();
+        let old = slot.map(|slot| slot.val);
+        let slot = slot.unwrap_or_default();
+        let sensitivity = self.settings.press_sensitivity;
+        let (press, release) = slot.threshold.unwrap_or((sensitivity, sensitivity));
+        if slot.consumed {
+            if val >= release { return }
+            self.action_val.slot_mut(&action).consumed = false;
+        }
+        if let Some(held) = slot.overridden {
+            let last_wins = matches!(self.rollover.get(&action), Some((_, Rollover::LastInputPriority)));
+            // pressing an overridden action again takes it back from the action that overrode it
+            if !(last_wins && held < press && val >= press) {
+                self.action_val.slot_mut(&action).overridden = Some(val);
+                return
+            }
+            self.action_val.slot_mut(&action).overridden = None;
+        }
+        let pressing = match slot.threshold {
+            Some(_) => slot.past_threshold,
+            None => slot.val.0 >= sensitivity
+        };
+        let pressed = val >= if pressing { release } else { press };
+        let jpressed = pressed && !pressing;
+        let rollover = self.rollover.get(&action).cloned();
+        if let (true, Some((other, Rollover::FirstInputPriority))) = (jpressed, &rollover) {
+            if self.is_pressing(other) {
+                self.action_val.slot_mut(&action).overridden = Some(val);
+                return
+            }
+        }
+        if jpressed && !self.cooldown_remaining(action.clone()).is_zero() {
+            // presses during a cooldown are ignored until let go, the same as `consume`
+            self.set_action_val(action.clone(), 0.0);
+            self.action_val.slot_mut(&action).consumed = true;
+            return
+        }
+        let released = !pressed && pressing;
+        let background = self.background_input && !self.focused;
+        if jpressed && background && !self.background_actions.contains(&action) { return }
+        let slot = self.action_val.slot_mut(&action);
+        slot.past_threshold = pressed && slot.threshold.is_some();
+        // a press earlier this loop still counts, such as from the first of two scroll steps
+        let was_jpressed = pressed && slot.val.1;
+        slot.val = (val, jpressed || was_jpressed, released);
+        if jpressed || released { self.tick_edge(action.clone(), jpressed, released) }
+        if jpressed { self.canceled.remove(&action); }
+        if old.map_or(val != 0.0, |o| o.0 != val) {
+            self.changed.insert(action.clone());
+            self.update_composites(&action);
+        }
+        if !self.subscribers.is_empty() {
+            let action = action.clone();
+            if jpressed { self.send_event(ActionEvent::Pressed(action, val)) }
+            else if released { self.send_event(ActionEvent::Released(action)) }
+            else if old.map(|o| o.0) != Some(val) { self.send_event(ActionEvent::Changed(action, val)) }
+        }
+        if let (Some(latency), true) = (&mut self.latency, old.map(|o| o.0) != Some(val)) {
+            latency.get_mut().unwrap().changed(action.clone())
+        }
+        if let (true, Some(source)) = (jpressed, self.event_source) {
+            self.action_sources.insert(action.clone(), source);
+        }
+        if jpressed && self.panic_action.as_ref() == Some(&action) { return self.reset() }
+        if jpressed {
+            self.record_press(action.clone());
+            if let Some((_, last)) = self.cooldowns.get_mut(&action) { *last = Some(Instant::now()) }
+            if let Some(toggled) = self.toggles.get_mut(&action) { *toggled = !*toggled }
+            self.advance_sequences(&action);
+        }
+        if released {
+            if let Some(t) = self.timing.get_mut(&action) { t.released_at = Some(Instant::now()) }
+        }
+        let canceled = self.canceled.contains(&action);
+        if let (true, false, Some(th)) = (released, canceled, self.tap_hold.get(&action).cloned()) {
+            let held = self.timing.get(&action).map(|t| t.pressed_at.elapsed());
+            if self.is_pressing(&th.hold) { self.set_action_val(th.hold, 0.0) }
+            else if held.is_some_and(|held| held < th.threshold) {
+                self.set_action_val(th.tap.clone(), 1.0);
+                self.tapped.push(th.tap);
+            }
+        }
+
+        let Some((other, policy)) = rollover else { return };
+        if jpressed && policy == Rollover::LastInputPriority && self.is_pressing(&other) {
+            let held = self.val(&other);
+            self.force_release(other.clone());
+            self.action_val.slot_mut(&other).overridden = Some(held);
+        } else if released {
+            if let Some(held) = self.action_val.slot_mut(&other).overridden.take() {
+                self.set_action_val(other, held);
+            }
+        }
+    }
+    /// lets go of action no matter what its bound to, with the same events and bookkeeping as
+    /// letting go of its binds. the release counts as canceled so it isnt taken as a tap
+    fn force_release(&mut self, action: F) {
+        let slot = self.action_val.slot_mut(&action);
+        (slot.overridden, slot.consumed) = (None, false);
+        let pressing = self.is_pressing(&action);
+        if pressing { self.canceled.insert(action.clone()); }
+        self.set_action_val(action, 0.0);
+    }
+    #[cfg(feature = "gamepad")]
+    fn update_gamepad(&mut self, event: gilrs::Event) {
+        let gilrs::Event { id, event, .. } = event;
+        self.event_source = Some(InputSource::Gamepad(id));
+        // gamepads with their own binds swap them in while their events are handled
+        let swap_binds = |s: &mut Self| if let Some(binds) = s.gamepad_binds.get_mut(&id) {
+            std::mem::swap(&mut s.binds, binds)
+        };
+        swap_binds(self);
+        self.handle_gamepad_event(id, event);
+        swap_binds(self);
+        self.event_source = None;
+    }
+    #[cfg(feature = "gamepad")]
+    fn handle_gamepad_event(&mut self, id: gilrs::GamepadId, event: gilrs::EventType) {
+        use gilrs::ev::EventType;
+        match event {
+            EventType::ButtonPressed(b, code) => {
+                let a = GamepadInput::from_button(b, code);
+                self.update_val(a.with_id(id), 1.0);
+                self.update_val(a.into(),      1.0);
+            },
+            EventType::ButtonReleased(b, code) => {
+                let a = GamepadInput::from_button(b, code);
+                self.update_val(a.with_id(id), 0.0);
+                self.update_val(a.into(),      0.0);
+            },
+            EventType::ButtonChanged(b, v, code) => {
+                let a = GamepadInput::from_button(b, code);
+                self.update_val(a.into(),      v);
+                self.update_val(a.with_id(id), v);
+            },
+            EventType::AxisChanged(b, v, _) => {
+                let dir_pos = v.max(0.0);
+                let dir_neg = (-v).max(0.0);
+                let input_pos = InputCode::gamepad_axis_pos(b);
+                let input_neg = InputCode::gamepad_axis_neg(b);
+
+                self.update_val(input_pos,                    dir_pos);
+                self.update_val(input_neg,                    dir_neg);
+                self.update_val(input_pos.set_gamepad_id(id), dir_pos);
+                self.update_val(input_neg.set_gamepad_id(id), dir_neg);
+            }
+            _ => ()
+        }
+    }
+    /// The value of a gamepad input without needing an action, from any gamepad if `id` is `None`
+    #[cfg(feature = "gamepad")]
+    fn gamepad_input_val(&self, input: GamepadInput, id: Option<gilrs::GamepadId>) -> f32 {
+        self.code_val(id.map_or(input.into(), |id| input.with_id(id)))
+    }
+    #[cfg(feature = "gamepad")]
+    fn stick(&self, x: GamepadAxis, y: GamepadAxis, id: Option<gilrs::GamepadId>) -> Vec2 {
+        let axis = |axis| self.gamepad_input_val(GamepadInput::Axis(axis, AxisSign::Pos), id)
+            - self.gamepad_input_val(GamepadInput::Axis(axis, AxisSign::Neg), id);
+        v(axis(x), axis(y))
+    }
+    /// Where the left stick of a gamepad is, or of the last gamepad to move it if `id` is `None`.
+    /// Works without any actions, for prototypes and debug controls
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # let input = input_map!();
+    /// let (x, y): (f32, f32) = input.left_stick(None).into();
+    /// ```
+    #[cfg(feature = "gamepad")]
+    pub fn left_stick(&self, id: Option<gilrs::GamepadId>) -> Vec2 {
+        self.stick(GamepadAxis::LeftStickX, GamepadAxis::LeftStickY, id)
+    }
+    /// Where the right stick of a gamepad is, see `left_stick`
+    #[cfg(feature = "gamepad")]
+    pub fn right_stick(&self, id: Option<gilrs::GamepadId>) -> Vec2 {
+        self.stick(GamepadAxis::RightStickX, GamepadAxis::RightStickY, id)
+    }
+    /// How far the left trigger of a gamepad is pulled, see `left_stick`
+    #[cfg(feature = "gamepad")]
+    pub fn left_trigger(&self, id: Option<gilrs::GamepadId>) -> f32 {
+        self.gamepad_input_val(GamepadInput::Button(GamepadButton::LeftTrigger2), id)
+    }
+    /// How far the right trigger of a gamepad is pulled, see `left_stick`
+    #[cfg(feature = "gamepad")]
+    pub fn right_trigger(&self, id: Option<gilrs::GamepadId>) -> f32 {
+        self.gamepad_input_val(GamepadInput::Button(GamepadButton::RightTrigger2), id)
+    }
+    /// The direction held on the dpad of a gamepad with positive being right and up, see
+    /// `left_stick`
+    #[cfg(feature = "gamepad")]
+    pub fn dpad_dir(&self, id: Option<gilrs::GamepadId>) -> Vec2 {
+        let button = |button| self.gamepad_input_val(GamepadInput::Button(button), id);
+        v(
+            button(GamepadButton::DPadRight) - button(GamepadButton::DPadLeft),
+            button(GamepadButton::DPadUp) - button(GamepadButton::DPadDown)
+        )
+    }
+    /// Checks if action is being pressed currently. same as `input.action_val(action) >=
+    /// input.press_sensitivity()` unless the action has its own thresholds, see `set_threshold`
+    pub fn pressing(&self, action: F) -> bool {
+        self.queried(&action);
+        self.is_pressing(&action)
+    }
+    /// Checks how much action is being pressed. May be higher than 1 in the case of scroll wheels
+    /// and mouse movement.
+    pub fn action_val(&self, action: F) -> f32 {
+        self.queried(&action);
+        self.val(&action)
+    }
+    /// the value of an action without counting as a query for latency tracking
+    fn val(&self, action: &F) -> f32 {
+        if let Some(&(v, _, _)) = self.action_val.get(action) { v } else {  0.0  }
+    }
+    /// if an action is being pressed without counting as a query for latency tracking
+    fn is_pressing(&self, action: &F) -> bool {
+        self.slot_pressing(self.action_val.slot(action))
+    }
+    /// if the action with this slot is being pressed
+    fn slot_pressing(&self, slot: Option<&ActionSlot>) -> bool {
+        match slot {
+            Some(slot) if slot.threshold.is_some() => slot.past_threshold,
+            Some(slot) => slot.val.0 >= self.settings.press_sensitivity,
+            None => false
+        }
+    }
+    /// Like `pressing` but takes a borrowed form of the action, so an `InputMap<String>` can be
+    /// checked with a `&str` without allocating. Actions stored with `with_dense_storage` are
+    /// searched for so those should use `pressing`
+    /// ```
+    /// # use winit_input_map::*;
+    /// use winit::keyboard::KeyCode;
+    /// let input = input_map!((String::from("jump"), KeyCode::Space));
+    /// assert!(!input.pressing_ref("jump"));
+    /// assert_eq!(input.action_val_ref("jump"), 0.0);
+    /// ```
+    pub fn pressing_ref<Q: ?Sized + Hash + Eq>(&self, action: &Q) -> bool where F: Borrow<Q> {
+        self.queried(action);
+        self.slot_pressing(self.action_val.slot_by(action))
+    }
+    /// Like `action_val` but takes a borrowed form of the action, see `pressing_ref`
+    pub fn action_val_ref<Q: ?Sized + Hash + Eq>(&self, action: &Q) -> f32 where F: Borrow<Q> {
+        self.queried(action);
+        self.action_val.slot_by(action).map_or(0.0, |slot| slot.val.0)
+    }
+    /// Like `pressed` but takes a borrowed form of the action, see `pressing_ref`
+    pub fn pressed_ref<Q: ?Sized + Hash + Eq>(&self, action: &Q) -> bool where F: Borrow<Q> {
+        self.queried(action);
+        self.action_val.slot_by(action).is_some_and(|slot| slot.val.1)
+    }
+    /// Like `released` but takes a borrowed form of the action, see `pressing_ref`
+    pub fn released_ref<Q: ?Sized + Hash + Eq>(&self, action: &Q) -> bool where F: Borrow<Q> {
+        self.queried(action);
+        self.action_val.slot_by(action).is_some_and(|slot| slot.val.2)
+    }
+    /// Like `axis` but takes borrowed forms of the actions, see `pressing_ref`
+    pub fn axis_ref<Q: ?Sized + Hash + Eq>(&self, pos: &Q, neg: &Q) -> f32 where F: Borrow<Q> {
+        self.action_val_ref(pos) - self.action_val_ref(neg)
+    }
+    /// records that an action was queried for latency tracking
+    fn queried<Q: ?Sized + Hash + Eq>(&self, action: &Q) where F: Borrow<Q> {
+        if let Some(latency) = &self.latency { latency.lock().unwrap().queried(action) }
+    }
+    /// Turns on measuring the time between an event changing an action and that action first
+    /// being queried, to find out how much latency the structure of your loop adds. Off by
+    /// default. See `latency_summary`
+    pub fn set_latency_tracking(&mut self, enabled: bool) {
+        self.latency = enabled.then(|| Mutex::new(Latency::new()));
+    }
+    /// Returns percentiles of the measured latency if latency tracking is on
+    pub fn latency_summary(&self) -> Option<LatencySummary> {
+        self.latency.as_ref().map(|latency| latency.lock().unwrap().summary())
+    }
+    /// checks if action was just pressed
+    pub fn pressed(&self, action: F) -> bool {
+        self.queried(&action);
+        if let Some(&(_, v, _)) = self.action_val.get(&action) { v } else { false }
+    }
+    /// The value of an input code, whether or not its bound to an action. Useful for debugging
+    /// tools and systems that dont know their inputs ahead of time. Remaps dont apply
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # use winit::keyboard::KeyCode;
+    /// # let input = input_map!();
+    /// if input.code_pressed(KeyCode::F3.into()) {
+    ///     println!("mouse x: {}", input.code_val(InputCode::MOUSE_MOVE_X_POS));
+    /// }
+    /// ```
+    pub fn code_val(&self, code: InputCode) -> f32 {
+        self.code_vals.get(&code).map_or(0.0, |&(v, _, _)| v)
+    }
+    /// Checks if an input code is being pressed, see `code_val`
+    pub fn code_pressing(&self, code: InputCode) -> bool {
+        self.code_val(code) >= self.settings.press_sensitivity
+    }
+    /// Checks if an input code was pressed this loop, see `code_val`
+    pub fn code_pressed(&self, code: InputCode) -> bool {
+        self.code_vals.get(&code).is_some_and(|&(_, v, _)| v)
+    }
+    /// Checks if an input code was released this loop, see `code_val`
+    pub fn code_released(&self, code: InputCode) -> bool {
+        self.code_vals.get(&code).is_some_and(|&(_, _, v)| v)
+    }
+    /// Runs `callback` with the value of action when `dispatch` is called after it was pressed.
+    /// Lets parts of a game react to input without checking every action every loop
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Pause }
+    /// # let mut input: InputMap<Action> = InputMap::default();
+    /// input.on_pressed(Action::Pause, |_| println!("paused"));
+    /// // after handling events
+    /// input.dispatch();
+    /// input.init();
+    /// ```
+    pub fn on_pressed(&mut self, action: F, callback: impl FnMut(f32) + Send + 'static) {
+        self.callbacks.push((action, CallbackTrigger::Pressed, Box::new(callback)));
+    }
+    /// Runs `callback` when `dispatch` is called after action was released, see `on_pressed`
+    pub fn on_released(&mut self, action: F, callback: impl FnMut(f32) + Send + 'static) {
+        self.callbacks.push((action, CallbackTrigger::Released, Box::new(callback)));
+    }
+    /// Runs `callback` when `dispatch` is called and the value of action is different to last
+    /// time, see `on_pressed`
+    pub fn on_changed(&mut self, action: F, callback: impl FnMut(f32) + Send + 'static) {
+        self.callbacks.push((action, CallbackTrigger::Changed, Box::new(callback)));
+    }
+    /// Removes the callbacks of an action
+    pub fn clear_callbacks(&mut self, action: F) {
+        self.callbacks.retain(|(a, _, _)| *a != action);
+    }
+    /// Runs the callbacks added with `on_pressed`, `on_released` and `on_changed`. Call once a
+    /// loop after handling events and before `init`. `InputApp` calls it before its update.
+    pub fn dispatch(&mut self) {
+        let mut callbacks = std::mem::take(&mut self.callbacks);
+        for (action, trigger, callback) in &mut callbacks {
+            let (_, pressed, released) = self.action_val.get(action).copied().unwrap_or_default();
+            let val = self.val(action);
+            let run = match trigger {
+                CallbackTrigger::Pressed => pressed,
+                CallbackTrigger::Released => released,
+                CallbackTrigger::Changed => self.dispatched.get(action).copied().unwrap_or(0.0) != val
+            };
+            if run { callback(val) }
+        }
+        for (action, _, _) in &callbacks {
+            self.dispatched.insert(action.clone(), self.val(action));
+        }
+        self.callbacks = callbacks;
+    }
+    /// Returns a channel that receives every press, release and change of each action as it
+    /// happens, so other threads such as audio or networking can react to input without sharing
+    /// the input map. Dropping the receiver unsubscribes it
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+    /// # enum Action { Fire }
+    /// # let mut input: InputMap<Action> = InputMap::default();
+    /// let events = input.subscribe();
+    /// std::thread::spawn(move || for event in events {
+    ///     if let ActionEvent::Pressed(Action::Fire, _) = event { println!("bang") }
+    /// });
+    /// ```
+    pub fn subscribe(&mut self) -> Receiver<ActionEvent<F>> {
+        let (sender, receiver) = channel();
+        self.subscribers.push(sender);
+        receiver
+    }
+    fn send_event(&mut self, event: ActionEvent<F>) {
+        self.subscribers.retain(|sender| sender.send(event.clone()).is_ok());
+    }
+    /// The actions that were pressed, released or changed value this loop, for systems that only
+    /// do work when something changes
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+    /// # enum Action { Jump }
+    /// # let input: InputMap<Action> = InputMap::default();
+    /// for action in input.changes().changed {
+    ///     println!("{action:?} is now {}", input.action_val(action));
+    /// }
+    /// ```
+    pub fn changes(&self) -> FrameChanges<F> {
+        let (mut pressed, mut released) = (vec![], vec![]);
+        for (action, &(_, p, r)) in self.action_val.iter() {
+            if p { pressed.push(action.clone()) }
+            if r { released.push(action.clone()) }
+        }
+        FrameChanges { pressed, released, changed: self.changed.iter().cloned().collect() }
+    }
+    /// How long its been since the last input, for auto pausing or attract modes. See
+    /// `idle_threshold` and `idle_ignore` for what counts
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # use std::time::Duration;
+    /// # let input = input_map!();
+    /// if input.idle_time() > Duration::from_secs(60) { /* start the demo */ }
+    /// ```
+    pub fn idle_time(&self) -> Duration {
+        self.last_input.elapsed()
+    }
+    /// The state of the last `history_len` loops, oldest first. Useful for combos, input displays
+    /// and seeing what was pressed right before something went wrong
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+    /// # enum Action { Jump }
+    /// # let mut input: InputMap<Action> = InputMap::default();
+    /// input.history_len = 120;
+    /// // when the player dies
+    /// let jumps = input.history().iter().filter(|frame| frame.state.pressed(Action::Jump)).count();
+    /// ```
+    pub fn history(&self) -> &VecDeque<HistoryFrame<F>> {
+        &self.history
+    }
+    /// Copies the state of every action and the mouse into an `InputState` that can be kept for
+    /// the whole frame or sent to other threads
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Jump }
+    /// # let input: InputMap<Action> = InputMap::default();
+    /// let state = input.snapshot();
+    /// std::thread::spawn(move || if state.pressed(Action::Jump) { /* jump */ });
+    /// ```
+    pub fn snapshot(&self) -> InputState<F> {
+        let actions = self.action_val.iter().map(|(action, &(val, pressed, released))|
+            (action.clone(), (val, self.is_pressing(action), pressed, released))
+        ).collect();
+        let (x, y) = self.mouse_delta;
+        InputState {
+            actions, cursor_pos: self.cursor_pos,
+            mouse_delta: (x as f32 * self.settings.mouse_scale_x, y as f32 * self.settings.mouse_scale_y)
+        }
+    }
+    /// Returns what action is doing this loop as a single state rather than a mix of `pressed`,
+    /// `pressing` and `released`
+    pub fn state(&self, action: F) -> ActionState {
+        if self.pressed(action.clone()) { ActionState::Started }
+        else if self.pressing(action.clone()) { ActionState::Ongoing }
+        else if self.canceled.contains(&action) && self.released(action.clone()) { ActionState::Canceled }
+        else if self.released(action) { ActionState::Completed }
+        else { ActionState::None }
+    }
+    /// Checks if action was just pressed or, after being held for `repeat_delay`, has repeated
+    /// every `repeat_interval` between the last two calls to `init`. The answer stays the same
+    /// for the whole loop. Works with any input, not just keys, so its useful for menu navigation
+    /// with gamepads.
+    pub fn pressed_or_repeat(&self, action: F) -> bool {
+        if self.pressed(action.clone()) { return true }
+        let Some(t) = self.timing.get(&action).filter(|_| self.pressing(action.clone())) else { return false };
+        let repeats = |at: Instant| {
+            let held = at.saturating_duration_since(t.pressed_at);
+            if held < self.repeat_delay { return 0 }
+            let interval = self.repeat_interval.as_secs_f32().max(f32::EPSILON);
+            1 + ((held - self.repeat_delay).as_secs_f32() / interval) as u32
+        };
+        repeats(self.last_init) > repeats(self.prev_init)
+    }
+    /// checks if action was just pressed within `double_press_time` of the previous press. A third
+    /// press in a row wont count as another double press
+    pub fn double_pressed(&self, action: F) -> bool {
+        self.pressed(action.clone()) && self.timing.get(&action).is_some_and(|t| t.double)
+    }
+    /// Checks if action was pressed within the last `duration`, even if it has been released since.
+    /// Useful for buffering inputs like jumping slightly before landing.
+    pub fn pressed_within(&self, action: F, duration: Duration) -> bool {
+        self.timing.get(&action).is_some_and(|t| t.pressed_at.elapsed() <= duration)
+    }
+    /// Returns how long action has been pressed for, or zero if it isnt being pressed
+    pub fn held_for(&self, action: F) -> Duration {
+        match self.timing.get(&action) {
+            Some(t) if self.pressing(action.clone()) => t.pressed_at.elapsed(),
+            _ => Duration::ZERO
+        }
+    }
+    /// Checks if action has been pressed for at least `duration`. Useful for long press
+    /// interactions
+    pub fn held_at_least(&self, action: F, duration: Duration) -> bool {
+        self.pressing(action.clone()) && self.held_for(action) >= duration
+    }
+    /// Makes action a 2D action driven by four sets of inputs, read with `axis_2d`. Its value is
+    /// the length of the direction so `pressed` and the rest still work. Its inputs go through
+    /// remaps, processors and inverting the same as binds to action
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Move }
+    /// # let mut input: InputMap<Action> = InputMap::default();
+    /// input.set_axis_2d(Action::Move, Axis2d::wasd().and(Axis2d::left_stick()));
+    /// let (x, y): (f32, f32) = input.axis_2d(Action::Move).into();
+    /// ```
+    pub fn set_axis_2d(&mut self, action: F, axis: crate::Axis2d) {
+        self.axes_2d.insert(action, axis);
+    }
+    /// The direction of a 2D action with positive being right and up, see `set_axis_2d`
+    pub fn axis_2d(&self, action: F) -> Vec2 {
+        self.queried(&action);
+        let (x, y) = self.axis_2d_dir(&action);
+        v(x, y)
+    }
+    /// Drives action from an expression over other actions, updated whenever they change. It gets
+    /// `pressed`, `released` and everything else like a bound action. Composites can use other
+    /// composites but shouldnt depend on themselves
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Shift, Forward, Sprint, ScrollUp, ScrollDown, Zoom }
+    /// use Action::*;
+    /// # let mut input: InputMap<Action> = InputMap::default();
+    /// input.add_composite(Sprint, ActionExpr::all(&[Shift, Forward]));
+    /// input.add_composite(Zoom, ActionExpr::axis(ScrollUp, ScrollDown));
+    /// ```
+    pub fn add_composite(&mut self, action: F, expr: ActionExpr<F>) {
+        self.composites.retain(|(composite, _)| *composite != action);
+        let val = expr.eval(&|a| self.val(a));
+        if self.val(&action) != val { self.set_action_val(action.clone(), val) }
+        self.composites.push((action, expr));
+    }
+    /// Stops action being driven by an expression, see `add_composite`
+    pub fn remove_composite(&mut self, action: F) {
+        self.composites.retain(|(composite, _)| *composite != action);
+    }
+    /// Adds a layer of binds used instead of `binds` while `layer` is held, like the shift layer of
+    /// a keyboard. Inputs the layer doesnt bind keep using `binds`, and an input pressed on the
+    /// layer stays on it until released even if `layer` is let go first. The first layer added
+    /// wins when several are held
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # use winit::keyboard::KeyCode;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Slot1, Slot2, Attack, Defend }
+    /// use Action::*;
+    /// let mut input = input_map!((Slot1, KeyCode::Digit1), (Slot2, KeyCode::Digit2));
+    /// input.add_layer(KeyCode::AltLeft, &[
+    ///     (Attack, vec![KeyCode::Digit1.into()]),
+    ///     (Defend, vec![KeyCode::Digit2.into()])
+    /// ]);
+    /// ```
+    pub fn add_layer(&mut self, layer: impl Into<InputCode>, binds: &[(F, Vec<InputCode>)]) -> LayerId {
+        let mut result = Binds::default();
+        for (action, codes) in binds {
+            for code in codes { result.map_mut().entry(*code).or_default().push(action.clone()) }
+        }
+        self.layers.push((layer.into(), result));
+        self.bind_cache_gen = None;
+        LayerId(self.layers.len() - 1)
+    }
+    /// Checks if the input of a layer is being held, see `add_layer`
+    pub fn layer_active(&self, id: LayerId) -> bool {
+        self.layers.get(id.0).is_some_and(|(layer, _)| self.code_pressing(*layer))
+    }
+    /// Groups actions with priorities so when an input is bound to several of them, only the
+    /// highest priority active one gets it. Actions with the same priority all get it. Use with
+    /// `set_action_active` for actions that only apply in some situations
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # use winit::keyboard::KeyCode;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Interact, Reload }
+    /// use Action::*;
+    /// let mut input = input_map!((Interact, KeyCode::KeyE), (Reload, KeyCode::KeyE));
+    /// input.add_exclusive_group(&[(Interact, 1), (Reload, 0)]);
+    /// // E reloads unless a prompt is showing
+    /// # let prompt_showing = false;
+    /// input.set_action_active(Interact, prompt_showing);
+    /// ```
+    pub fn add_exclusive_group(&mut self, actions: &[(F, i32)]) {
+        let group = self.exclusive.values().map(|&(group, _)| group + 1).max().unwrap_or(0);
+        for (action, priority) in actions {
+            self.exclusive.insert(action.clone(), (group, *priority));
+        }
+    }
+    /// Sets if an action receives input, releasing it if its being pressed. Actions are active
+    /// by default
+    pub fn set_action_active(&mut self, action: F, active: bool) {
+        if active { self.inactive.remove(&action); return }
+        if self.val(&action) != 0.0 { self.set_action_val(action.clone(), 0.0) }
+        self.axis_2d_vals.retain(|(a, _), _| *a != action);
+        self.inactive.insert(action);
+    }
+    /// Checks if an action receives input, see `set_action_active`
+    pub fn action_active(&self, action: F) -> bool {
+        !self.inactive.contains(&action)
+    }
+    /// Handles the press of an action so nothing else reacts to it. `pressed` and `pressing` are
+    /// false until the input is let go and pressed again, and it shows as `released` this loop like
+    /// a `reset`. Useful when a dialog handles a press
+    /// that gameplay shouldnt also see
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Confirm }
+    /// # let mut input: InputMap<Action> = InputMap::default();
+    /// # let dialog_open = true;
+    /// if dialog_open && input.pressed(Action::Confirm) {
+    ///     input.consume(Action::Confirm);
+    ///     // close the dialog
+    /// }
+    /// ```
+    pub fn consume(&mut self, action: F) {
+        if !self.is_pressing(&action) { return }
+        self.force_release(action.clone());
+        self.action_val.slot_mut(&action).consumed = true;
+    }
+    /// Stops action from being pressed again until `cooldown` has passed since its last press.
+    /// Presses during the cooldown are ignored until theyre let go, the same as `consume`. Use
+    /// `Duration::ZERO` to remove it
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # use std::time::Duration;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Dash }
+    /// # let mut input: InputMap<Action> = InputMap::default();
+    /// input.set_cooldown(Action::Dash, Duration::from_secs(2));
+    /// let ready = 1.0 - input.cooldown_remaining(Action::Dash).as_secs_f32() / 2.0;
+    /// ```
+    pub fn set_cooldown(&mut self, action: F, cooldown: Duration) {
+        if cooldown.is_zero() { self.cooldowns.remove(&action); }
+        else { self.cooldowns.entry(action).or_insert((cooldown, None)).0 = cooldown; }
+    }
+    /// How long until action can be pressed again, see `set_cooldown`
+    pub fn cooldown_remaining(&self, action: F) -> Duration {
+        match self.cooldowns.get(&action) {
+            Some(&(cooldown, Some(last))) => cooldown.saturating_sub(last.elapsed()),
+            _ => Duration::ZERO
+        }
+    }
+    /// How far a hold has got towards `charge_duration` from 0 to 1, for charge attacks and
+    /// drawing bows. On the loop action is released it returns how far the hold got, otherwise
+    /// its 0 while not pressing
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # use std::time::Duration;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Shoot }
+    /// # let input: InputMap<Action> = InputMap::default();
+    /// let draw = input.hold_progress(Action::Shoot, Duration::from_secs(1));
+    /// if input.released(Action::Shoot) { /* fire an arrow with a speed of `draw` */ }
+    /// ```
+    pub fn hold_progress(&self, action: F, charge_duration: Duration) -> f32 {
+        let Some(t) = self.timing.get(&action) else { return 0.0 };
+        let held = match t.released_at {
+            Some(released_at) if self.released(action.clone()) => released_at - t.pressed_at,
+            _ if self.pressing(action) => t.pressed_at.elapsed(),
+            _ => return 0.0
+        };
+        if charge_duration.is_zero() { return 1.0 }
+        (held.as_secs_f32() / charge_duration.as_secs_f32()).min(1.0)
+    }
+    /// If action was just released, returns how long it was held for. Useful for charge attacks
+    pub fn released_after(&self, action: F) -> Option<Duration> {
+        if !self.released(action.clone()) { return None }
+        let t = self.timing.get(&action)?;
+        Some(t.released_at?.saturating_duration_since(t.pressed_at))
+    }
+    /// checks if action was just released
+    pub fn released(&self, action: F) -> bool {
+        self.queried(&action);
+        if let Some(&(_, _, v)) = self.action_val.get(&action) { v } else { false }
+    }
+    /// Like `pressed` but for a fixed timestep update. Presses are kept across calls to `init` until
+    /// `consume_tick` is called, so a press isnt missed when a loop has no ticks or counted twice
+    /// when it has several.
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # use std::time::Duration;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Jump }
+    /// # let mut input: InputMap<Action> = InputMap::default();
+    /// # let (mut accumulator, tick) = (Duration::ZERO, Duration::from_secs(1) / 60);
+    /// while accumulator >= tick {
+    ///     if input.tick_pressed(Action::Jump) { println!("jump") }
+    ///     input.consume_tick();
+    ///     accumulator -= tick;
+    /// }
+    /// input.init();
+    /// ```
+    pub fn tick_pressed(&self, action: F) -> bool {
+        self.queried(&action);
+        self.tick_edges.get(&action).is_some_and(|e| e.0)
+    }
+    /// Like `released` but for a fixed timestep update, see `tick_pressed`
+    pub fn tick_released(&self, action: F) -> bool {
+        self.queried(&action);
+        self.tick_edges.get(&action).is_some_and(|e| e.1)
+    }
+    /// Clears the presses and releases seen by `tick_pressed` and `tick_released`. Call at the end
+    /// of each fixed timestep tick.
+    pub fn consume_tick(&mut self) {
+        self.tick_edges.clear();
+    }
+    /// Returns f32 based on how much pos and neg are pressed. may return values higher than 1.0 in
+    /// the case of mouse movement and scrolling. usefull for movement controls. for 2d values see
+    /// `[dir]` and `[dir_max_len_1]`
+    /// ```
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Pos, Neg }
+    /// # use Action::*;
+    /// # let input: InputMap<Action> = InputMap::default();
+    /// let move_dir = input.axis(Neg, Pos);
+    /// ```
+    /// same as `input.action_val(pos) - input.action_val(neg)`
+    pub fn axis(&self, pos: F, neg: F) -> f32 {
+        self.action_val(pos) - self.action_val(neg)
+    }
+    /// Like `axis` but for several actions each way, using the most pressed action on each side
+    pub fn axis_any(&self, pos: &[F], neg: &[F]) -> f32 {
+        self.val_max(pos) - self.val_max(neg)
+    }
+    /// The value of the most pressed action
+    pub fn val_max(&self, actions: &[F]) -> f32 {
+        actions.iter().map(|action| self.action_val(action.clone())).fold(0.0, f32::max)
+    }
+    /// Checks if any of the actions were pressed this loop
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Confirm, Jump }
+    /// # let input: InputMap<Action> = InputMap::default();
+    /// if input.pressed_any(&[Action::Confirm, Action::Jump]) { /* skip the cutscene */ }
+    /// ```
+    pub fn pressed_any(&self, actions: &[F]) -> bool {
+        actions.iter().any(|action| self.pressed(action.clone()))
+    }
+    /// Checks if any of the actions are being pressed
+    pub fn pressing_any(&self, actions: &[F]) -> bool {
+        actions.iter().any(|action| self.pressing(action.clone()))
+    }
+    /// Checks if all of the actions are being pressed, such as holding both grips
+    pub fn pressing_all(&self, actions: &[F]) -> bool {
+        actions.iter().all(|action| self.pressing(action.clone()))
+    }
+    /// Checks if any of the actions were released this loop
+    pub fn released_any(&self, actions: &[F]) -> bool {
+        actions.iter().any(|action| self.released(action.clone()))
+    }
+    /// How much of action was input per second since `init` was last called. Mouse movement and
+    /// scrolling add up between calls to `init`, so this makes them independent of the framerate.
+    /// Check it right after handling events, as the time keeps counting until the next `init`.
+    pub fn action_rate(&self, action: F) -> f32 {
+        self.action_val(action) / self.since_init()
+    }
+    /// same as `input.action_rate(pos) - input.action_rate(neg)`
+    pub fn axis_rate(&self, pos: F, neg: F) -> f32 {
+        self.axis(pos, neg) / self.since_init()
+    }
+    /// seconds since the last `init`, kept above 0 so rates dont divide by 0
+    fn since_init(&self) -> f32 {
+        self.last_init.elapsed().as_secs_f32().max(0.0001)
+    }
+    /// Turns a held axis into a number of discrete steps to take this loop, for scrubbing through
+    /// timelines or scrolling lists with a stick. The rate scales with how far the axis is pushed,
+    /// being slow near the center and reaching `max_steps_per_sec` at the edge. Call once a loop.
+    pub fn scrub(&mut self, pos: F, neg: F, max_steps_per_sec: f32) -> i32 {
+        let axis = self.axis(pos.clone(), neg.clone()).clamp(-1.0, 1.0);
+        let now = Instant::now();
+        let (partial, last) = self.scrubs.entry((pos, neg)).or_insert((0.0, now));
+        if axis == 0.0 { *partial = 0.0 }
+        // squared so small movements near the center are easier to control
+        *partial += axis * axis.abs() * max_steps_per_sec * (now - *last).as_secs_f32();
+        *last = now;
+        let steps = partial.trunc();
+        *partial -= steps;
+        steps as i32
+    }
+    /// Returns a vector based off of x and y axis. For movement controls see `dir_max_len_1`
     pub fn dir(&self, pos_x: F, neg_x: F, pos_y: F, neg_y: F) -> Vec2 {
         v(self.axis(pos_x, neg_x), self.axis(pos_y, neg_y))
     }
@@ -324,4 +3270,491 @@ impl<F: Hash + Eq + Clone + Copy> InputMap<F> {
         let length = (x*x + y*y).sqrt().max(1.0);
         v(x/length, y/length)
     }
+    /// The length of the vector from `dir`, so how far a stick is pushed
+    pub fn axis_magnitude(&self, pos_x: F, neg_x: F, pos_y: F, neg_y: F) -> f32 {
+        let (x, y) = (self.axis(pos_x, neg_x), self.axis(pos_y, neg_y));
+        (x*x + y*y).sqrt()
+    }
+    /// The angle of the vector from `dir` in radians counterclockwise from the right, or `None`
+    /// if its within `settings.angle_deadzone` of the center. Useful for twin stick aiming
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { AimRight, AimLeft, AimUp, AimDown }
+    /// use Action::*;
+    /// # let input: InputMap<Action> = InputMap::default();
+    /// # let mut ship_angle = 0.0;
+    /// if let Some(angle) = input.axis_angle(AimRight, AimLeft, AimUp, AimDown) {
+    ///     ship_angle = angle;
+    /// }
+    /// ```
+    pub fn axis_angle(&self, pos_x: F, neg_x: F, pos_y: F, neg_y: F) -> Option<f32> {
+        let (x, y) = (self.axis(pos_x, neg_x), self.axis(pos_y, neg_y));
+        if (x*x + y*y).sqrt() <= self.settings.angle_deadzone { return None }
+        Some(y.atan2(x))
+    }
+    /// Returns a 3D vector based off of x, y and z axis, for fly cameras and space games. For
+    /// movement controls see `dir3_max_len_1`
+    pub fn dir3(&self, pos_x: F, neg_x: F, pos_y: F, neg_y: F, pos_z: F, neg_z: F) -> Vec3 {
+        v3(self.axis(pos_x, neg_x), self.axis(pos_y, neg_y), self.axis(pos_z, neg_z))
+    }
+    /// Returns a 3D vector based off of x, y and z axis with a maximum length of 1. If this is
+    /// undesirable see `dir3`
+    pub fn dir3_max_len_1(&self, pos_x: F, neg_x: F, pos_y: F, neg_y: F, pos_z: F, neg_z: F) -> Vec3 {
+        let (x, y, z) = (self.axis(pos_x, neg_x), self.axis(pos_y, neg_y), self.axis(pos_z, neg_z));
+        let length = (x*x + y*y + z*z).sqrt().max(1.0);
+        v3(x/length, y/length, z/length)
+    }
+    /// Returns true while the cursor is over the window. Its false until the cursor first moves
+    /// over it, and after it leaves.
+    pub fn cursor_in_window(&self) -> bool {
+        self.cursor_inside
+    }
+    /// Returns the mouse position while the cursor is over the window and not captured by
+    /// `set_mouse_captured`, otherwise `None`.
+    /// `mouse_pos` keeps the last position the cursor was seen at, which is usually stale once
+    /// it leaves, so use this for aiming and hover effects.
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Shoot }
+    /// # let input: InputMap<Action> = InputMap::default();
+    /// if let Some(pos) = input.mouse_pos_in_window() {
+    ///     println!("aiming at {pos:?}")
+    /// }
+    /// ```
+    pub fn mouse_pos_in_window(&self) -> Option<Vec2> {
+        (self.cursor_inside && !self.mouse_captured).then_some(self.mouse_pos)
+    }
+    /// Returns the mouse position in logical pixels using the windows scale factor.
+    pub fn mouse_pos_logical(&self) -> Vec2 {
+        let pos = self.cursor_pos.to_logical::<f32>(self.scale_factor);
+        v(pos.x, pos.y)
+    }
+    /// The direction from `point` to the mouse with a length of 1, or zero if the mouse is on
+    /// it. `point` is in pixels like `mouse_pos`, such as the player's position on screen. Up is
+    /// positive the same as sticks, so mouse and stick aiming can share code
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { AimRight, AimLeft, AimUp, AimDown }
+    /// use Action::*;
+    /// # let input: InputMap<Action> = InputMap::default();
+    /// # let player_on_screen = Default::default();
+    /// let aim = match input.last_input_source() {
+    ///     Some(InputSource::Mouse) => input.aim_dir_from(player_on_screen),
+    ///     _ => input.dir_max_len_1(AimRight, AimLeft, AimUp, AimDown)
+    /// };
+    /// ```
+    pub fn aim_dir_from(&self, point: Vec2) -> Vec2 {
+        let (x, y) = xy(point);
+        let (x, y) = (self.cursor_pos.x as f32 - x, y - self.cursor_pos.y as f32);
+        let length = (x*x + y*y).sqrt();
+        if length <= f32::EPSILON { return v(0.0, 0.0) }
+        v(x/length, y/length)
+    }
+    /// Returns the mouse position relative to the window size where (0, 0) is the top left and
+    /// (1, 1) is the bottom right. Make sure `window_size` is set if the window hasnt been resized
+    /// yet.
+    pub fn mouse_pos_normalized(&self) -> Vec2 {
+        let (x, y) = self.normalize(self.cursor_pos);
+        v(x, y)
+    }
+    /// Returns the mouse position in normalised device coordinates where (-1, -1) is the bottom left
+    /// and (1, 1) is the top right, the same as shaders use.
+    pub fn mouse_pos_ndc(&self) -> Vec2 {
+        let (w, h) = (self.window_size.width.max(1) as f32, self.window_size.height.max(1) as f32);
+        v(self.cursor_pos.x as f32 / w * 2.0 - 1.0, 1.0 - self.cursor_pos.y as f32 / h * 2.0)
+    }
+    /// Returns the drag of `button` once the mouse has moved `drag_threshold` pixels while its held.
+    /// Useful for box selecting, sliders and camera panning.
+    pub fn drag(&self, button: MouseButton) -> Option<Drag> {
+        let drag = self.drags.get(&button).filter(|d| d.started)?;
+        let (start, pos) = (drag.start, self.cursor_pos);
+        Some(Drag {
+            start: v(start.x as f32, start.y as f32),
+            delta: v((pos.x - start.x) as f32, (pos.y - start.y) as f32),
+            ended: drag.ended
+        })
+    }
+    /// Returns the fingers currently on the touch screen, along with ones lifted this loop
+    pub fn touches(&self) -> impl Iterator<Item = Touch> + '_ {
+        self.touches.iter().map(|(&id, t)| Touch {
+            id,
+            pos: v(t.pos.x as f32, t.pos.y as f32),
+            start: v(t.start.x as f32, t.start.y as f32),
+            phase: t.phase,
+            force: t.force
+        })
+    }
+    /// Returns the mouse while its over the window and every touch, including ones lifted this
+    /// loop.
+    pub fn pointers(&self) -> impl Iterator<Item = Pointer> + '_ {
+        self.cursor_inside.then(|| self.mouse_pointer()).into_iter()
+            .chain(self.touches().map(|t| Pointer {
+                id: PointerId::Touch(t.id),
+                pos: t.pos,
+                pressed: !matches!(t.phase, TouchPhase::Ended | TouchPhase::Cancelled),
+                pressure: t.force
+            }))
+    }
+    /// Returns the pointer a single pointer app should follow. This is the first touch still held
+    /// if there is one, otherwise the mouse. When it lifts the oldest other touch takes over.
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # let input = input_map!();
+    /// let pointer = input.primary_pointer();
+    /// if pointer.pressed { println!("clicked at {:?}", pointer.pos) }
+    /// ```
+    pub fn primary_pointer(&self) -> Pointer {
+        self.primary_touch.and_then(|id| self.pointer(PointerId::Touch(id)))
+            .unwrap_or_else(|| self.mouse_pointer())
+    }
+    /// Returns a pointer by id if its over the window or touching the screen
+    pub fn pointer(&self, id: PointerId) -> Option<Pointer> {
+        self.pointers().find(|p| p.id == id)
+    }
+    fn mouse_pointer(&self) -> Pointer {
+        Pointer {
+            id: PointerId::Mouse,
+            pos: v(self.cursor_pos.x as f32, self.cursor_pos.y as f32),
+            pressed: self.drags.get(&MouseButton::Left).is_some_and(|d| !d.ended),
+            pressure: None
+        }
+    }
+    /// How hard the pen is pressing from 0-1 while its touching the screen. winit doesnt tell pens
+    /// and fingers apart, so on devices that report finger pressure this will be a finger too.
+    pub fn pen_pressure(&self) -> Option<f32> {
+        self.pen.map(|(pressure, _)| pressure)
+    }
+    /// How far the pen is tilted from 0 when its upright to 1 when its flat, while its touching the
+    /// screen. Only supported on iOS
+    pub fn pen_tilt(&self) -> Option<f32> {
+        self.pen.and_then(|(_, tilt)| tilt)
+    }
+    /// Presses action while a touch that started inside of `region` is held, like a button taking
+    /// up part of the screen. Make sure `window_size` is set if the window hasnt been resized yet.
+    /// ```
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Move, Look }
+    /// let mut input: InputMap<Action> = InputMap::default();
+    /// input.add_touch_region(Action::Move, TouchRegion::LEFT_HALF);
+    /// input.add_touch_region(Action::Look, TouchRegion::RIGHT_HALF);
+    /// ```
+    pub fn add_touch_region(&mut self, action: F, region: TouchRegion) {
+        self.touch_regions.push((region, action));
+    }
+    /// Adds a joystick that appears wherever a touch starts inside of its region, the usual
+    /// movement controls on mobile. It gives its value through the `DeviceInput::JoystickX` and
+    /// `JoystickY` codes, so its usually fed into a 2D action with `Axis2d::virtual_joystick`.
+    /// ```
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Move }
+    /// let mut input: InputMap<Action> = InputMap::default();
+    /// let stick = input.add_virtual_joystick(VirtualJoystick {
+    ///     region: TouchRegion::LEFT_HALF,
+    ///     radius: 100.0,
+    ///     recenter: true
+    /// });
+    /// input.set_axis_2d(Action::Move, Axis2d::virtual_joystick(stick).and(Axis2d::wasd()));
+    /// // every loop
+    /// let movement = input.axis_2d(Action::Move);
+    /// if let Some(center) = input.joystick_center(stick) { /* draw the joystick */ }
+    /// ```
+    pub fn add_virtual_joystick(&mut self, joystick: VirtualJoystick) -> JoystickId {
+        self.joysticks.push((joystick, None));
+        JoystickId(self.joysticks.len() - 1)
+    }
+    /// Returns the center of a virtual joystick in physical pixels while a touch is controlling it
+    pub fn joystick_center(&self, id: JoystickId) -> Option<Vec2> {
+        let (_, center) = self.joysticks.get(id.0)?.1?;
+        Some(v(center.x as f32, center.y as f32))
+    }
+    /// Removes every touch region that presses action
+    pub fn remove_touch_regions(&mut self, action: F) {
+        self.touch_regions.retain(|(_, a)| *a != action);
+    }
+    /// Takes the text typed and text editing keys pressed this loop in the order they happened.
+    /// Unlike `text_typed` this keeps track of backspaces and arrow keys in between typing.
+    /// ```
+    /// # use winit_input_map::*;
+    /// # use winit::keyboard::KeyCode;
+    /// # let mut input = input_map!();
+    /// let mut text = String::new();
+    /// for event in input.drain_text() {
+    ///     match event {
+    ///         TextEvent::Text(new) => text.push_str(&new),
+    ///         TextEvent::Key(KeyCode::Backspace) => { text.pop(); },
+    ///         _ => ()
+    ///     }
+    /// }
+    /// ```
+    pub fn drain_text(&mut self) -> impl Iterator<Item = TextEvent> + '_ {
+        self.text_events.drain(..)
+    }
+    /// Allows action to be pressed while the window isnt focused, see `background_input`
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # use winit::{keyboard::KeyCode, event_loop::{EventLoop, DeviceEvents}};
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { PushToTalk }
+    /// let event_loop = EventLoop::new().unwrap();
+    /// // without this winit stops sending device events when the window loses focus
+    /// event_loop.listen_device_events(DeviceEvents::Always);
+    ///
+    /// let mut input = input_map!((Action::PushToTalk, KeyCode::KeyV));
+    /// input.background_input = true;
+    /// input.set_background(Action::PushToTalk, true);
+    /// ```
+    pub fn set_background(&mut self, action: F, allowed: bool) {
+        if allowed { self.background_actions.insert(action); }
+        else { self.background_actions.remove(&action); }
+    }
+    /// Checks if the window has focus
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+    /// Turns text input mode on or off, such as when a chat box is focused. While its on the IME
+    /// is allowed on `window` and keys that type text dont press actions, so typing "w" doesnt
+    /// also move the player. Keys like Enter and Escape still press actions so they can be used
+    /// to close the chat box.
+    pub fn set_text_input_mode(&mut self, window: &Window, enabled: bool) {
+        self.text_input_mode = enabled;
+        window.set_ime_allowed(enabled);
+        if !enabled { self.preedit = None }
+    }
+    /// Grabs and hides the cursor on `window` for mouse look, or releases and shows it again.
+    /// While its captured the mouse position stops updating, so aim with the relative
+    /// `MouseMove` codes or `mouse_delta` instead of `mouse_pos`. The cursor is locked in place
+    /// where the platform supports it and confined to the window otherwise. Errors if neither
+    /// works, in which case the cursor is left visible.
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Pause }
+    /// # let mut input: InputMap<Action> = InputMap::default();
+    /// # let window: winit::window::Window = todo!();
+    /// if input.pressed(Action::Pause) {
+    ///     let captured = input.mouse_captured();
+    ///     input.set_mouse_captured(&window, !captured).ok();
+    /// }
+    /// ```
+    pub fn set_mouse_captured(&mut self, window: &Window, captured: bool) -> Result<(), ExternalError> {
+        if captured {
+            window.set_cursor_grab(CursorGrabMode::Locked)
+                .or_else(|_| window.set_cursor_grab(CursorGrabMode::Confined))?;
+        } else {
+            window.set_cursor_grab(CursorGrabMode::None)?;
+        }
+        window.set_cursor_visible(!captured);
+        self.mouse_captured = captured;
+        self.release_capture = false;
+        Ok(())
+    }
+    /// Checks if the cursor is captured for mouse look, see `set_mouse_captured`
+    pub fn mouse_captured(&self) -> bool {
+        self.mouse_captured
+    }
+    /// Checks if text input mode is on, see `set_text_input_mode`
+    pub fn text_input_mode(&self) -> bool {
+        self.text_input_mode
+    }
+    /// Returns the files dropped on the window this loop, in the order they were dropped
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # let input = input_map!();
+    /// for path in input.dropped_files() {
+    ///     println!("opening {}", path.display())
+    /// }
+    /// ```
+    pub fn dropped_files(&self) -> &[PathBuf] {
+        &self.dropped_files
+    }
+    /// Returns the files being dragged over the window that havent been dropped yet, useful for
+    /// highlighting where they will go
+    pub fn hovered_files(&self) -> &[PathBuf] {
+        &self.hovered_files
+    }
+    /// Returns the text being composed with an IME that hasnt been committed yet, along with the
+    /// byte range of its cursor if it should be shown. Committed text is added to `text_typed` and
+    /// `drain_text`
+    pub fn ime_preedit(&self) -> Option<(&str, Option<(usize, usize)>)> {
+        self.preedit.as_ref().map(|(text, cursor)| (text.as_str(), *cursor))
+    }
+    /// Returns a name for an input code to show in UI. Keys use what they have typed on the users
+    /// keyboard layout once they've been pressed without modifiers, so KeyQ shows as "A" on
+    /// AZERTY, and fall back to their QWERTY name before that. Extra mouse buttons are named after
+    /// the number most mice print on them.
+    /// ```
+    /// # use winit_input_map::*;
+    /// use winit::event::MouseButton;
+    /// let input = input_map!();
+    /// assert_eq!(input.key_display_name(MouseButton::Back.into()), "Back Mouse");
+    /// assert_eq!(input.key_display_name(MouseButton::Other(8).into()), "Mouse 8");
+    /// ```
+    pub fn key_display_name(&self, code: InputCode) -> String {
+        let (modifiers, input) = match code {
+            InputCode::Device { input, .. } => (ModifiersState::empty(), input),
+            InputCode::Modified { modifiers, input, .. } => (modifiers, input),
+            #[cfg(feature = "gamepad")]
+            InputCode::Gamepad { input, .. } => return format!("{input:?}")
+        };
+        let mut name = String::new();
+        for (modifier, mod_name) in [
+            (ModifiersState::CONTROL, "Ctrl+"), (ModifiersState::ALT, "Alt+"),
+            (ModifiersState::SHIFT, "Shift+"), (ModifiersState::SUPER, "Super+")
+        ] {
+            if modifiers.contains(modifier) { name.push_str(mod_name) }
+        }
+        match input {
+            DeviceInput::Key(key) => match (self.key_names.get(&key), key) {
+                (Some(typed), _) => name.push_str(typed),
+                (None, PhysicalKey::Code(code)) => {
+                    let code = format!("{code:?}");
+                    name.push_str(code.strip_prefix("Key").or(code.strip_prefix("Digit")).unwrap_or(&code))
+                },
+                (None, key) => name.push_str(&format!("{key:?}"))
+            },
+            DeviceInput::Character(c) => name.extend(c.to_uppercase()),
+            // side buttons are often numbered on the mouse itself
+            DeviceInput::Button(MouseButton::Other(n)) => name.push_str(&format!("Mouse {n}")),
+            DeviceInput::Button(button) => name.push_str(&format!("{button:?} Mouse")),
+            input => name.push_str(&format!("{input:?}"))
+        }
+        name
+    }
+    /// Returns the last input code pressed this loop that is one of `kinds`, along with the value
+    /// that pressed it. Useful for rebinding without mouse movement getting in the way
+    /// ```
+    /// # use winit_input_map::*;
+    /// # let input = input_map!();
+    /// if let Some((code, _)) = input.recently_pressed_filtered(&InputKind::BUTTONS) {
+    ///     println!("rebinding to {code:?}");
+    /// }
+    /// ```
+    pub fn recently_pressed_filtered(&self, kinds: &[InputKind]) -> Option<(InputCode, f32)> {
+        self.pressed_this_loop.iter().rev().find(|(code, _)| kinds.contains(&code.kind())).copied()
+    }
+    /// Returns every input code pressed this loop in order, along with the value that pressed it
+    pub fn pressed_this_loop(&self) -> &[(InputCode, f32)] {
+        &self.pressed_this_loop
+    }
+    /// The kind of device that last pressed something, even if it isnt bound to anything. Useful
+    /// for switching button prompts between keyboard and gamepad as soon as the player switches.
+    /// Mouse movement and scrolling only count if they go past `press_sensitivity`
+    pub fn last_input_source(&self) -> Option<InputSource> {
+        self.last_source
+    }
+    /// The kind of device that last pressed action
+    pub fn action_source(&self, action: F) -> Option<InputSource> {
+        self.action_sources.get(&action).copied()
+    }
+    /// The device `recently_pressed` came from, for assigning devices to players. Only known for
+    /// window events and device events passed in with `update_with_device_event_from`
+    pub fn recent_device(&self) -> Option<DeviceId> {
+        self.recent_device
+    }
+    /// Returns the modifier keys currently held
+    pub fn modifiers(&self) -> ModifiersState {
+        self.modifiers
+    }
+    /// Returns how much the mouse moved this loop, scaled by the mouse scale in `settings`. Unlike
+    /// the `MOUSE_MOVE` input codes this doesnt need any actions to be bound.
+    pub fn mouse_delta(&self) -> Vec2 {
+        let (x, y) = (self.mouse_delta.0 as f32, self.mouse_delta.1 as f32);
+        v(x * self.settings.mouse_scale_x, y * self.settings.mouse_scale_y)
+    }
+    /// The left and right mouse sensitivity, `settings.mouse_scale_y` is up and down
+    pub fn mouse_scale(&self) -> f32 {
+        self.settings.mouse_scale_x
+    }
+    /// Sets the mouse sensitivity for both directions
+    pub fn set_mouse_scale(&mut self, scale: f32) {
+        self.settings.set_mouse_scale(scale)
+    }
+    /// How much mouse wheel scrolling is scaled by, see `settings`
+    pub fn scroll_scale(&self) -> f32 {
+        self.settings.scroll_scale
+    }
+    /// Sets how much mouse wheel scrolling is scaled by
+    pub fn set_scroll_scale(&mut self, scale: f32) {
+        self.settings.scroll_scale = scale
+    }
+    /// The minimum value something has to be at to count as being pressed
+    pub fn press_sensitivity(&self) -> f32 {
+        self.settings.press_sensitivity
+    }
+    /// Sets the minimum value something has to be at to count as being pressed
+    pub fn set_press_sensitivity(&mut self, sensitivity: f32) {
+        self.settings.press_sensitivity = sensitivity
+    }
+    /// copies the deprecated sensitivity fields into `settings` if they were set since the last
+    /// sync, then copies `settings` back so reading them stays right
+    #[allow(deprecated)]
+    fn sync_deprecated_settings(&mut self) {
+        let [mouse, scroll, press] = self.synced_deprecated;
+        if self.mouse_scale != mouse { self.settings.set_mouse_scale(self.mouse_scale) }
+        if self.scroll_scale != scroll { self.settings.scroll_scale = self.scroll_scale }
+        if self.press_sensitivity != press {
+            self.settings.press_sensitivity = self.press_sensitivity
+        }
+        self.mouse_scale = self.settings.mouse_scale_x;
+        self.scroll_scale = self.settings.scroll_scale;
+        self.press_sensitivity = self.settings.press_sensitivity;
+        self.synced_deprecated = [self.mouse_scale, self.scroll_scale, self.press_sensitivity];
+    }
+    /// Returns how much the mouse moved this loop without `mouse_scale` applied.
+    pub fn mouse_delta_raw(&self) -> Vec2 {
+        v(self.mouse_delta.0 as f32, self.mouse_delta.1 as f32)
+    }
+    /// Returns the mouse position in pixels without rounding it to an `f32`, for apps like CAD
+    /// tools that need to be exact far from the origin
+    pub fn mouse_pos_f64(&self) -> (f64, f64) {
+        (self.cursor_pos.x, self.cursor_pos.y)
+    }
+    /// Returns how much the mouse moved this loop without `mouse_scale` or rounding to an `f32`
+    pub fn mouse_delta_f64(&self) -> (f64, f64) {
+        self.mouse_delta
+    }
+    /// Returns how much was scrolled this loop as (x, y) without any scaling or rounding to an
+    /// `f32`. Its in lines, with smooth scrolling from touchpads turned into lines using
+    /// `settings.scroll_step_pixels` so both can be used together
+    pub fn scroll_delta_f64(&self) -> (f64, f64) {
+        self.scroll_delta
+    }
+    /// How many whole steps were scrolled this loop as (x, y), with positive being right and up.
+    /// Useful for switching weapons or moving through a list one item at a time, see
+    /// `settings.scroll_step_pixels`
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # let input = input_map!();
+    /// # let (mut weapon, weapons) = (0, 4);
+    /// let (_, steps) = input.scroll_steps();
+    /// weapon = (weapon + steps).rem_euclid(weapons);
+    /// ```
+    pub fn scroll_steps(&self) -> (i32, i32) {
+        self.scroll_steps
+    }
+    /// The scrolling this loop plus whats left of earlier scrolling as it slows down, scaled by
+    /// the scroll scales in `settings`. Positive is right and up. Without
+    /// `settings.scroll_half_life` its only this loop
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # use std::time::Duration;
+    /// # let mut input = input_map!();
+    /// # let mut zoom = 1.0;
+    /// input.settings.scroll_half_life = Duration::from_millis(100);
+    /// // every loop
+    /// let (_, y): (f32, f32) = input.scroll_velocity().into();
+    /// zoom *= 1.0 + y * 0.1;
+    /// ```
+    pub fn scroll_velocity(&self) -> Vec2 {
+        let axis = |pos, neg| self.code_val(pos) - self.code_val(neg);
+        v(
+            axis(InputCode::MOUSE_SCROLL_X_POS, InputCode::MOUSE_SCROLL_X_NEG),
+            axis(InputCode::MOUSE_SCROLL_POS, InputCode::MOUSE_SCROLL_NEG)
+        )
+    }
 }
@@ -1,9 +1,12 @@
-use winit::keyboard::{ KeyCode, PhysicalKey };
+use winit::keyboard::{ KeyCode, PhysicalKey, ModifiersState, Key, NamedKey };
 use winit::event::*;
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 /// Enum that specifies an input
 pub enum InputCode {
     Device { id: SpecifyDevice, input: DeviceInput },
+    /// A device input that only counts while exactly `modifiers` are held. While it matches, binds
+    /// to the same input without modifiers arent pressed.
+    Modified { modifiers: ModifiersState, id: SpecifyDevice, input: DeviceInput },
     #[cfg(feature = "gamepad")]
     Gamepad { id: SpecifyGamepad, input: GamepadInput }
 }
@@ -40,6 +43,22 @@ impl InputCode {
         input: DeviceInput::MouseScroll(AxisSign::Neg),
         id: SpecifyDevice::Any
     };
+    pub const PINCH_OUT: Self = Self::Device {
+        input: DeviceInput::Pinch(AxisSign::Pos),
+        id: SpecifyDevice::Any
+    };
+    pub const PINCH_IN: Self = Self::Device {
+        input: DeviceInput::Pinch(AxisSign::Neg),
+        id: SpecifyDevice::Any
+    };
+    pub const ROTATE_CCW: Self = Self::Device {
+        input: DeviceInput::Rotation(AxisSign::Pos),
+        id: SpecifyDevice::Any
+    };
+    pub const ROTATE_CW: Self = Self::Device {
+        input: DeviceInput::Rotation(AxisSign::Neg),
+        id: SpecifyDevice::Any
+    };
     #[cfg(feature = "gamepad")]
     pub fn gamepad_axis_pos(axis: gilrs::Axis) -> Self {
         GamepadInput::Axis(axis, AxisSign::Pos).into()
@@ -54,6 +73,7 @@ impl InputCode {
             #[cfg(feature = "gamepad")]
             Self::Gamepad { input, .. } => input.into(),
             Self::Device  { input, .. } => input.into(),
+            Self::Modified { modifiers, input, .. } => input.with_modifiers(modifiers),
         }
     }
     #[cfg(feature = "gamepad")]
@@ -62,12 +82,92 @@ impl InputCode {
         if let Self::Gamepad { input, .. } = self { input.with_id(id) }
         else { self }
     }
-    #[allow(irrefutable_let_patterns)]
     /// sets the device id. if its a gamepad it does nothing.
     pub fn set_device_id(self, id: DeviceId) -> Self {
-        if let Self::Device { input, .. } = self { input.with_id(id) }
+        match self {
+            Self::Device { input, .. } => input.with_id(id),
+            Self::Modified { modifiers, input, .. } => Self::Modified {
+                modifiers, input, id: SpecifyDevice::Id(id)
+            },
+            #[cfg(feature = "gamepad")]
+            _ => self
+        }
+    }
+    /// requires `modifiers` to be held for a device input to count. if its a gamepad it does
+    /// nothing.
+    pub fn with_modifiers(self, modifiers: ModifiersState) -> Self {
+        match self {
+            Self::Device { id, input } | Self::Modified { id, input, .. } =>
+                Self::Modified { modifiers, id, input },
+            #[cfg(feature = "gamepad")]
+            _ => self
+        }
+    }
+    /// gets the wildcard input code that this input code is part of, keeping the device or
+    /// gamepad it's from
+    pub fn wildcard(self) -> Option<Self> {
+        match self {
+            Self::Device { id, input: DeviceInput::Key(_)    } => Some(DeviceInput::AnyKey.with_sid(id)),
+            Self::Device { id, input: DeviceInput::Button(_) } => Some(DeviceInput::AnyMouseButton.with_sid(id)),
+            #[cfg(feature = "gamepad")]
+            Self::Gamepad { id, input: GamepadInput::Button(_) | GamepadInput::Other(_) } =>
+                Some(GamepadInput::AnyButton.with_sid(id)),
+            _ => None
+        }
+    }
+    /// gets what kind of input this is
+    pub fn kind(self) -> InputKind {
+        match self {
+            Self::Device { input, .. } | Self::Modified { input, .. } => match input {
+                DeviceInput::Key(_) | DeviceInput::Character(_) | DeviceInput::Named(_)
+                | DeviceInput::AnyKey => InputKind::Key,
+                DeviceInput::Button(_) | DeviceInput::RawButton(_)
+                | DeviceInput::AnyMouseButton => InputKind::MouseButton,
+                DeviceInput::MouseMoveX(_) | DeviceInput::MouseMoveY(_) => InputKind::MouseMove,
+                DeviceInput::DeviceAxis(..) => InputKind::DeviceAxis,
+                DeviceInput::MouseScroll(_) | DeviceInput::MouseScrollX(_)
+                | DeviceInput::ScrollStep(_) | DeviceInput::ScrollStepX(_) => InputKind::MouseScroll,
+                DeviceInput::Pinch(_) | DeviceInput::Rotation(_) | DeviceInput::PanX(_)
+                | DeviceInput::PanY(_) | DeviceInput::DoubleTap
+                | DeviceInput::JoystickX(..) | DeviceInput::JoystickY(..) => InputKind::Gesture,
+                DeviceInput::PenPressure | DeviceInput::PenTilt => InputKind::Pen,
+            },
+            #[cfg(feature = "gamepad")]
+            Self::Gamepad { input, .. } => match input {
+                GamepadInput::Button(_) | GamepadInput::AnyButton | GamepadInput::Other(_)
+                | GamepadInput::TouchpadTouch | GamepadInput::TouchpadClick => InputKind::GamepadButton,
+                GamepadInput::Axis(..) | GamepadInput::TouchpadX(_)
+                | GamepadInput::TouchpadY(_) => InputKind::GamepadAxis,
+                GamepadInput::Gyro(..) | GamepadInput::Accel(..) => InputKind::Motion
+            }
+        }
+    }
+    /// removes the modifiers needed for a device input to count
+    pub fn without_modifiers(self) -> Self {
+        if let Self::Modified { id, input, .. } = self { input.with_sid(id) }
         else { self }
     }
+    /// the sign of the axis this input code is for, or `None` if its not an axis
+    pub fn axis_sign(self) -> Option<AxisSign> {
+        match self {
+            Self::Device { input, .. } | Self::Modified { input, .. } => match input {
+                DeviceInput::MouseMoveX(sign)   | DeviceInput::MouseMoveY(sign)
+                | DeviceInput::MouseScroll(sign) | DeviceInput::MouseScrollX(sign)
+                | DeviceInput::ScrollStep(sign) | DeviceInput::ScrollStepX(sign)
+                | DeviceInput::Pinch(sign) | DeviceInput::Rotation(sign)
+                | DeviceInput::PanX(sign)  | DeviceInput::PanY(sign)
+                | DeviceInput::JoystickX(_, sign) | DeviceInput::JoystickY(_, sign)
+                | DeviceInput::DeviceAxis(_, sign) => Some(sign),
+                _ => None
+            },
+            #[cfg(feature = "gamepad")]
+            Self::Gamepad { input: GamepadInput::Axis(_, sign)
+                | GamepadInput::Gyro(_, sign) | GamepadInput::Accel(_, sign)
+                | GamepadInput::TouchpadX(sign) | GamepadInput::TouchpadY(sign), .. } => Some(sign),
+            #[cfg(feature = "gamepad")]
+            Self::Gamepad { .. } => None
+        }
+    }
     pub fn set_axis_sign(self, sign: AxisSign) -> Self {
         match self {
             Self::Device { id, input } => match input {
@@ -75,11 +175,26 @@ impl InputCode {
                 DeviceInput::MouseMoveY(_)   => DeviceInput::MouseMoveY(sign)  .with_sid(id),
                 DeviceInput::MouseScroll(_)  => DeviceInput::MouseScroll(sign) .with_sid(id),
                 DeviceInput::MouseScrollX(_) => DeviceInput::MouseScrollX(sign).with_sid(id),
+                DeviceInput::ScrollStep(_)   => DeviceInput::ScrollStep(sign)  .with_sid(id),
+                DeviceInput::ScrollStepX(_)  => DeviceInput::ScrollStepX(sign) .with_sid(id),
+                DeviceInput::Pinch(_)        => DeviceInput::Pinch(sign)       .with_sid(id),
+                DeviceInput::Rotation(_)     => DeviceInput::Rotation(sign)    .with_sid(id),
+                DeviceInput::PanX(_)         => DeviceInput::PanX(sign)        .with_sid(id),
+                DeviceInput::PanY(_)         => DeviceInput::PanY(sign)        .with_sid(id),
+                DeviceInput::JoystickX(j, _) => DeviceInput::JoystickX(j, sign).with_sid(id),
+                DeviceInput::JoystickY(j, _) => DeviceInput::JoystickY(j, sign).with_sid(id),
+                DeviceInput::DeviceAxis(axis, _) => DeviceInput::DeviceAxis(axis, sign).with_sid(id),
                 result =>                       result                         .with_sid(id)
             },
+            Self::Modified { modifiers, id, input } =>
+                input.with_sid(id).set_axis_sign(sign).with_modifiers(modifiers),
             #[cfg(feature = "gamepad")]
             Self::Gamepad { id, input } => match input {
                 GamepadInput::Axis(axis, _) => GamepadInput::Axis(axis, sign).with_sid(id),
+                GamepadInput::Gyro(axis, _) => GamepadInput::Gyro(axis, sign).with_sid(id),
+                GamepadInput::Accel(axis, _) => GamepadInput::Accel(axis, sign).with_sid(id),
+                GamepadInput::TouchpadX(_)   => GamepadInput::TouchpadX(sign)   .with_sid(id),
+                GamepadInput::TouchpadY(_)   => GamepadInput::TouchpadY(sign)   .with_sid(id),
                 result =>                      result                        .with_sid(id)
             }
         }
@@ -101,6 +216,11 @@ impl From<PhysicalKey> for InputCode {
         Self::Device { id: SpecifyDevice::Any, input: value.into() }
     }
 }
+impl From<NamedKey> for InputCode {
+    fn from(value: NamedKey) -> Self {
+        Self::Device { id: SpecifyDevice::Any, input: value.into() }
+    }
+}
 impl From<KeyCode> for InputCode {
     fn from(value: KeyCode) -> Self {
         Self::Device { id: SpecifyDevice::Any, input: value.into() }
@@ -115,13 +235,59 @@ where F: Into<DeviceInput> + Sized {
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum DeviceInput {
     Button(MouseButton),
+    /// A mouse button from raw device events by its platform specific id, such as 1 for the left
+    /// button on X11 but 0 on macOS. Only pressed while background input is on and the window
+    /// isnt focused, see `InputMap::background_input`
+    RawButton(ButtonId),
     Key(PhysicalKey),
+    /// A key by the character it types on the users keyboard layout rather than its position.
+    /// Always lowercase, so shift doesnt change it
+    Character(char),
+    /// A key by its meaning on the users keyboard layout, such as `NamedKey::Enter`
+    Named(NamedKey),
     MouseMoveX(AxisSign),
     MouseMoveY(AxisSign),
     MouseScroll(AxisSign),
     /// axis for left and right mouse scroll. most mice cant do this but it is common with
     /// touchpads
     MouseScrollX(AxisSign),
+    /// pressed for one loop for each step of the scroll wheel, with a value of how many steps
+    /// were scrolled that loop. Smooth scrolling counts as a step for every
+    /// `InputSettings::scroll_step_pixels` pixels
+    ScrollStep(AxisSign),
+    /// the same as `ScrollStep` for left and right scrolling
+    ScrollStepX(AxisSign),
+    /// pressed while any key is held. useful for "press any key" screens
+    AnyKey,
+    /// pressed while any mouse button is held
+    AnyMouseButton,
+    /// two finger pinch on a touchpad or touch screen. pos is spreading the fingers apart to zoom
+    /// in
+    Pinch(AxisSign),
+    /// two finger rotation in degrees. pos is counterclockwise
+    Rotation(AxisSign),
+    /// pan gesture in pixels, scaled by `InputSettings::pan_scale`
+    PanX(AxisSign),
+    PanY(AxisSign),
+    /// pressed for one loop when a double tap gesture happens
+    DoubleTap,
+    /// how far a virtual joystick from `InputMap::add_virtual_joystick` is pushed, from 0 at its
+    /// center to 1 at its radius. pos is right and up. See `Axis2d::virtual_joystick`
+    JoystickX(crate::JoystickId, AxisSign),
+    JoystickY(crate::JoystickId, AxisSign),
+    /// how hard a pen or finger is pressing on a touch screen from 0-1, on devices that support it
+    PenPressure,
+    /// how far a pen is tilted from 0 when its upright to 1 when its flat against the screen. only
+    /// supported on iOS
+    PenTilt,
+    /// an axis of a device by its platform specific id, such as a 3D mouse or flight stick that
+    /// gilrs doesnt support. Like mouse movement its how much the axis moved this loop, unless
+    /// its made absolute with `InputMap::set_device_axis_absolute` for axes that report a
+    /// position, such as throttles. The value is
+    /// whatever the device reports so it usually needs scaling with `Processor::Scale`. Mice
+    /// report their movement on axis 0 and 1 too on some platforms, use `MouseMoveX` and
+    /// `MouseMoveY` for them instead
+    DeviceAxis(AxisId, AxisSign),
 }
 impl DeviceInput {
     pub fn with_id(self, id: DeviceId) -> InputCode {
@@ -130,6 +296,25 @@ impl DeviceInput {
     pub fn with_sid(self, id: SpecifyDevice) -> InputCode {
         InputCode::Device { id, input: self }
     }
+    /// requires `modifiers` to be held for this input to count, such as shift click
+    pub fn with_modifiers(self, modifiers: ModifiersState) -> InputCode {
+        InputCode::Modified { modifiers, id: SpecifyDevice::Any, input: self }
+    }
+    /// gets the logical key input of a winit key. `None` if its a character made of multiple
+    /// chars, such as from a dead key, or is unidentified
+    pub fn logical(key: &Key) -> Option<Self> {
+        match key {
+            Key::Named(named) => Some(Self::Named(*named)),
+            Key::Character(string) => {
+                let mut chars = string.chars().flat_map(char::to_lowercase);
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Some(Self::Character(c)),
+                    _ => None
+                }
+            },
+            _ => None
+        }
+    }
 }
 impl From<MouseButton> for DeviceInput {
     fn from(value: MouseButton) -> Self {
@@ -146,6 +331,67 @@ impl From<PhysicalKey> for DeviceInput {
         Self::Key(value)
     }
 }
+impl From<NamedKey> for DeviceInput {
+    fn from(value: NamedKey) -> Self {
+        Self::Named(value)
+    }
+}
+/// A mouse button that can be written as a string and parsed back, for saving binds. Buttons are
+/// written as "MouseLeft", "MouseBack", "Mouse8" and so on
+/// ```
+/// # use winit_input_map::*;
+/// use winit::event::MouseButton;
+/// for button in [MouseButton::Left, MouseButton::Back, MouseButton::Forward, MouseButton::Other(8)] {
+///     let name = MouseButtonName(button);
+///     assert_eq!(name.to_string().parse(), Ok(name));
+/// }
+/// assert_eq!(MouseButtonName(MouseButton::Other(8)).to_string(), "Mouse8");
+/// let input: DeviceInput = "MouseBack".parse::<MouseButtonName>().unwrap().into();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MouseButtonName(pub MouseButton);
+impl std::fmt::Display for MouseButtonName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            MouseButton::Other(n) => write!(f, "Mouse{n}"),
+            button => write!(f, "Mouse{button:?}")
+        }
+    }
+}
+impl std::str::FromStr for MouseButtonName {
+    type Err = ParseInputError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseInputError(s.to_string());
+        let button = match s.strip_prefix("Mouse").ok_or_else(error)? {
+            "Left" => MouseButton::Left,
+            "Right" => MouseButton::Right,
+            "Middle" => MouseButton::Middle,
+            "Back" => MouseButton::Back,
+            "Forward" => MouseButton::Forward,
+            n => MouseButton::Other(n.parse().map_err(|_| error())?)
+        };
+        Ok(Self(button))
+    }
+}
+impl From<MouseButtonName> for DeviceInput {
+    fn from(value: MouseButtonName) -> Self {
+        Self::Button(value.0)
+    }
+}
+impl From<MouseButtonName> for InputCode {
+    fn from(value: MouseButtonName) -> Self {
+        DeviceInput::Button(value.0).into()
+    }
+}
+/// A string that isnt a mouse button that can be parsed, see `MouseButtonName`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseInputError(pub String);
+impl std::fmt::Display for ParseInputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\"{}\" isnt an input that can be parsed", self.0)
+    }
+}
+impl std::error::Error for ParseInputError {}
 /// specify device to listen to. defaults to any and can be specified later on at runtime
 #[derive(Default, Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum SpecifyDevice {
@@ -157,7 +403,36 @@ pub enum SpecifyDevice {
     Any
 }
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AxisSign { Pos, Neg }
+impl AxisSign {
+    pub fn opposite(self) -> Self {
+        match self { Self::Pos => Self::Neg, Self::Neg => Self::Pos }
+    }
+}
+/// The kind of an input code, see `InputCode::kind`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum InputKind {
+    Key,
+    MouseButton,
+    MouseMove,
+    MouseScroll,
+    /// Touchpad and touch screen gestures such as pinching, and virtual joysticks
+    Gesture,
+    /// Pen pressure and tilt
+    Pen,
+    GamepadButton,
+    GamepadAxis,
+    /// Other device axis, see `DeviceInput::DeviceAxis`
+    DeviceAxis,
+    /// Gamepad gyro and accelerometer
+    Motion
+}
+impl InputKind {
+    /// Kinds that are pressed on purpose, leaving out mouse movement, scrolling, gestures, pens and
+    /// gamepad axis which are easy to trigger by accident while rebinding
+    pub const BUTTONS: [Self; 3] = [Self::Key, Self::MouseButton, Self::GamepadButton];
+}
 #[cfg(feature = "gamepad")]
 pub use gamepad::*;
 #[cfg(feature = "gamepad")]
@@ -166,9 +441,56 @@ mod gamepad {
     pub type GamepadAxis = gilrs::Axis;
     pub type GamepadButton = gilrs::Button;
     #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum GamepadInput {
         Button(GamepadButton),
-        Axis(GamepadAxis, crate::AxisSign)
+        Axis(GamepadAxis, crate::AxisSign),
+        /// pressed while any button is held
+        AnyButton,
+        /// A button gilrs doesnt know, such as the back grips and paddles of the Steam Deck or
+        /// extra capture buttons, by its raw event code. These are used instead of
+        /// `Button(GamepadButton::Unknown)` so each button can be bound separately
+        Other(u32),
+        /// How fast the gamepad is rotating in degrees per second, scaled by
+        /// `InputSettings::gyro_scale`. Needs to be fed in with `InputMap::update_motion` as gilrs
+        /// doesnt read motion sensors
+        Gyro(MotionAxis, crate::AxisSign),
+        /// Acceleration of the gamepad in g, including gravity. Needs to be fed in with
+        /// `InputMap::update_motion`
+        Accel(MotionAxis, crate::AxisSign),
+        /// Where a finger is on the touchpad of gamepads such as the DualSense, from -1 to 1 with
+        /// positive being right and up. Needs to be fed in with `InputMap::update_touchpad`
+        TouchpadX(crate::AxisSign),
+        TouchpadY(crate::AxisSign),
+        /// pressed while a finger is on the touchpad
+        TouchpadTouch,
+        /// pressed while the touchpad is clicked in
+        TouchpadClick
+    }
+    /// An axis of a gamepads motion sensors. For the gyro X is pitch, Y is yaw and Z is roll
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum MotionAxis { X, Y, Z }
+    impl MotionAxis {
+        pub const PITCH: Self = Self::X;
+        pub const YAW:   Self = Self::Y;
+        pub const ROLL:  Self = Self::Z;
+    }
+    /// A reading from a gamepads motion sensors, see `InputMap::update_motion`
+    #[derive(Debug, Default, PartialEq, Clone, Copy)]
+    pub struct Motion {
+        /// Rotation speed in degrees per second around the pitch, yaw and roll axes
+        pub gyro: [f32; 3],
+        /// Acceleration in g along the x, y and z axes, including gravity
+        pub accel: [f32; 3]
+    }
+    /// The state of a gamepads touchpad, see `InputMap::update_touchpad`
+    #[derive(Debug, Default, PartialEq, Clone, Copy)]
+    pub struct Touchpad {
+        /// Where the finger is from -1 to 1, with positive being right and up. `None` when not
+        /// touched
+        pub position: Option<[f32; 2]>,
+        pub clicked: bool
     }
     impl GamepadInput {
         pub fn with_id(self, id: gilrs::GamepadId) -> InputCode {
@@ -183,6 +505,15 @@ mod gamepad {
             GamepadInput::Button(value)
         }
     }
+    impl GamepadInput {
+        /// The input for a button event, using `Other` for buttons gilrs doesnt know
+        pub fn from_button(button: GamepadButton, code: gilrs::ev::Code) -> Self {
+            match button {
+                GamepadButton::Unknown => Self::Other(code.into_u32()),
+                button => Self::Button(button)
+            }
+        }
+    }
     impl From<GamepadInput> for InputCode {
         fn from(value: GamepadInput) -> InputCode {
             Self::Gamepad { input: value, id: Default::default() }
@@ -193,6 +524,47 @@ mod gamepad {
             Self::Gamepad { input: GamepadInput::Button(value), id: Default::default() }
         }
     }
+    /// Information about a connected gamepad, see `InputMap::gamepads`
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct GamepadInfo {
+        pub id: gilrs::GamepadId,
+        pub name: String,
+        /// Stays the same for the same model of gamepad, even after reconnecting
+        pub uuid: [u8; 16],
+        pub power: gilrs::PowerInfo,
+        /// Where the layout of the gamepad comes from. If its `MappingSource::None` most of its
+        /// inputs will be unknown
+        pub mapping_source: gilrs::MappingSource
+    }
+    impl GamepadInfo {
+        /// The battery level from 0-100 if the gamepad is running on its battery
+        pub fn battery(&self) -> Option<u8> {
+            if let gilrs::PowerInfo::Discharging(level) = self.power { Some(level) } else { None }
+        }
+    }
+    impl From<gilrs::Gamepad<'_>> for GamepadInfo {
+        fn from(gamepad: gilrs::Gamepad) -> Self {
+            Self {
+                id: gamepad.id(),
+                name: gamepad.name().to_string(),
+                uuid: gamepad.uuid(),
+                power: gamepad.power_info(),
+                mapping_source: gamepad.mapping_source()
+            }
+        }
+    }
+    /// The binds `InputMap::set_gamepad_binds` gave one gamepad, for keeping them between runs
+    /// with `InputMap::saved_gamepad_binds` and `InputMap::restore_gamepad_binds`. Gamepads of the
+    /// same model are told apart by `index`, their place among the gamepads with that uuid in the
+    /// order of their ids, so player 2s Xbox controller gets player 2s binds back. Can be
+    /// serialized with the `serde` feature
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct SavedGamepadBinds<F> {
+        pub uuid: [u8; 16],
+        pub index: usize,
+        pub binds: Vec<(F, Vec<GamepadInput>)>
+    }
     /// Specify gamepad to listen to. defaults to any and can be specified later on at runtime
     #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
     pub enum SpecifyGamepad {
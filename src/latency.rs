@@ -0,0 +1,51 @@
+use std::collections::{HashMap, VecDeque};
+use web_time::{Duration, Instant};
+use std::{borrow::Borrow, cmp::Eq, hash::Hash};
+/// How many of the most recent samples are kept
+const MAX_SAMPLES: usize = 1024;
+/// Percentiles of the time between an event changing an action and the action being queried.
+/// See `InputMap::latency_summary`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LatencySummary {
+    /// How many samples the summary is made from
+    pub samples: usize,
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    pub max: Duration
+}
+/// Records when actions are changed by events and how long until they get queried
+#[derive(Debug, Clone)]
+pub(crate) struct Latency<F> {
+    pending: HashMap<F, Instant>,
+    samples: VecDeque<Duration>
+}
+impl<F: Hash + Eq + Clone> Latency<F> {
+    pub(crate) fn new() -> Self {
+        Self { pending: HashMap::new(), samples: VecDeque::new() }
+    }
+    /// called when an event changes the action. only the first change before a query counts
+    pub(crate) fn changed(&mut self, action: F) {
+        self.pending.entry(action).or_insert_with(Instant::now);
+    }
+    /// called when the action is queried
+    pub(crate) fn queried<Q: ?Sized + Hash + Eq>(&mut self, action: &Q) where F: Borrow<Q> {
+        if let Some(changed_at) = self.pending.remove(action) {
+            if self.samples.len() == MAX_SAMPLES { self.samples.pop_front(); }
+            self.samples.push_back(changed_at.elapsed());
+        }
+    }
+    pub(crate) fn summary(&self) -> LatencySummary {
+        let mut sorted: Vec<Duration> = self.samples.iter().copied().collect();
+        sorted.sort_unstable();
+        let percentile = |p: f32| sorted.get(((sorted.len() as f32 - 1.0) * p).round() as usize)
+            .copied().unwrap_or_default();
+        LatencySummary {
+            samples: sorted.len(),
+            p50: percentile(0.5),
+            p90: percentile(0.9),
+            p99: percentile(0.99),
+            max: sorted.last().copied().unwrap_or_default()
+        }
+    }
+}
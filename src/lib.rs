@@ -4,11 +4,11 @@
 //! (through `dir` and `dir_max_len_1`). This makes it easy to have things like 3d camera controls
 //! applied for both mouse movement and the gamepads right stick. The input map also can get the
 //! `mouse_pos`, what was `recently_pressed` (used for rebinding things) and the `text_typed`
-//! (useful for typing) to make sure it is fully featured. For better user control there are the
-//! `mouse_scale` and `scroll_scale` variables to make sensitivity align with eveything else
-//! 0-1 range and a `press_sensitivity` to control when a action counts as being pressed. Finaly,
+//! (useful for typing) to make sure it is fully featured. For better user control the `settings`
+//! have mouse and scroll scales to make sensitivity align with eveything else 0-1 range and a
+//! `press_sensitivity` to control when a action counts as being pressed. Finaly,
 //! theres an input_map! macro to reduce boilerplate and increase readability.
-//! ```
+//! ```no_run
 //! use winit::{
 //!     window::*, application::*, keyboard::*,
 //!     event_loop::*, event::*
@@ -62,16 +62,82 @@
 //!     }
 //! }
 //! ```
+#[cfg(all(feature = "glium-types", feature = "glam"))]
+compile_error!("the glium-types and glam features cant be used together");
 mod input;
 mod input_code;
+mod action;
+mod binds;
+mod settings;
+mod bind_menu;
+mod latency;
+mod processor;
+mod action_expr;
+mod axis2d;
+mod text_field;
+mod multi_window;
+mod app;
+mod shared;
+#[cfg(feature = "gamepad")]
+mod gyro;
+#[cfg(feature = "gamepad")]
+mod flick_stick;
+#[cfg(feature = "haptics")]
+mod haptics;
+#[cfg(feature = "prompts")]
+mod prompt;
 pub use crate::input::*;
 pub use crate::input_code::*;
+pub use crate::action::IndexedAction;
+pub use crate::binds::Binds;
+pub use crate::settings::*;
+pub use crate::bind_menu::*;
+pub use crate::latency::LatencySummary;
+pub use crate::processor::*;
+pub use crate::action_expr::*;
+pub use crate::axis2d::*;
+pub use crate::text_field::*;
+pub use crate::multi_window::*;
+pub use crate::app::*;
+pub use crate::shared::*;
+#[cfg(feature = "gamepad")]
+pub use crate::gyro::*;
+#[cfg(feature = "gamepad")]
+pub use crate::flick_stick::*;
+#[cfg(feature = "haptics")]
+pub use crate::haptics::*;
+#[cfg(feature = "prompts")]
+pub use crate::prompt::*;
+/// Creates an enum of actions that implements `IndexedAction`, so it can be used with
+/// `InputMap::with_dense_storage`. The enum also derives everything an action needs
+/// ```
+/// use winit_input_map::*;
+/// use winit::keyboard::KeyCode;
+/// actions! {
+///     pub enum Action { Jump, Left, Right }
+/// }
+/// let input = input_map!((Action::Jump, KeyCode::Space)).with_dense_storage();
+/// assert_eq!(Action::COUNT, 3);
+/// assert!(!input.pressing(Action::Jump));
+/// ```
+#[macro_export]
+macro_rules! actions {
+    ( $( #[$meta:meta] )* $vis:vis enum $name:ident { $( $variant:ident ),* $(,)? } ) => {
+        $( #[$meta] )*
+        #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+        $vis enum $name { $( $variant ),* }
+        impl $crate::IndexedAction for $name {
+            const COUNT: usize = [$( stringify!($variant) ),*].len();
+            fn index(&self) -> usize { *self as usize }
+        }
+    };
+}
 /// Creates new input map with inputed input codes bound to the acompaning action.
 /// Anything that impliments `into<InputCode>` can be bound to an action
 /// ```
 /// use Action::*;
 /// use winit_input_map::*;
-/// use winit::{keyboard::KeyCode, event::MouseButton}
+/// use winit::{keyboard::KeyCode, event::MouseButton};
 /// #[derive(Hash, PartialEq, Eq, Clone, Copy)]
 /// enum Action {
 ///     Jump,
@@ -81,8 +147,8 @@ pub use crate::input_code::*;
 /// }
 /// let mut input = input_map!(
 ///     (Jump,     KeyCode::Space                    ),
-///     (Left,     KeyCode::KeyA, KeyCode::LeftArrow ),
-///     (Right,    KeyCode::KeyD, KeyCode::RightArrow),
+///     (Left,     KeyCode::KeyA, KeyCode::ArrowLeft ),
+///     (Right,    KeyCode::KeyD, KeyCode::ArrowRight),
 ///     (Interact, MouseButton::Left                 )
 /// );
 /// ```
@@ -0,0 +1,96 @@
+use crate::InputMap;
+use winit::{event::{WindowEvent, DeviceEvent, DeviceId}, window::WindowId};
+use std::collections::HashMap;
+use std::{cmp::Eq, hash::Hash};
+/// Keeps a separate `InputMap` for each window so events from one window dont affect another.
+/// Each window gets its own mouse position, text and pressed actions, with device and gamepad
+/// events going to the focused window. The input map of each window is made by the function given
+/// to `new`, so they all start with the same binds and settings.
+/// ```no_run
+/// # use winit_input_map::*;
+/// # use winit::{event::*, window::WindowId, keyboard::KeyCode};
+/// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+/// # enum Action { Close }
+/// # let (id, event): (WindowId, WindowEvent) = todo!();
+/// let mut input = MultiWindowInput::new(|| input_map!((Action::Close, KeyCode::Escape)));
+/// // in `window_event`
+/// input.update_with_window_event(id, &event);
+/// // in `about_to_wait`
+/// if input.window(id).is_some_and(|input| input.pressed(Action::Close)) { /* close the window */ }
+/// input.init();
+/// ```
+pub struct MultiWindowInput<F: Hash + Eq + Clone> {
+    /// Makes the input map of each new window
+    pub make_input: MakeInputMap<F>,
+    windows: HashMap<WindowId, InputMap<F>>,
+    focused: Option<WindowId>
+}
+/// Makes the input map of a new window, see `MultiWindowInput::new`
+pub type MakeInputMap<F> = Box<dyn Fn() -> InputMap<F>>;
+impl<F: Hash + Eq + Clone> MultiWindowInput<F> {
+    pub fn new(make_input: impl Fn() -> InputMap<F> + 'static) -> Self {
+        Self { make_input: Box::new(make_input), windows: HashMap::new(), focused: None }
+    }
+    /// Updates the input map of the window the event is from, creating it if its a new window
+    pub fn update_with_window_event(&mut self, id: WindowId, event: &WindowEvent) {
+        match event {
+            WindowEvent::Focused(true) => self.focused = Some(id),
+            WindowEvent::Focused(false) if self.focused == Some(id) => self.focused = None,
+            WindowEvent::Destroyed => {
+                self.remove_window(id);
+                return
+            },
+            _ => ()
+        }
+        self.window_or_insert(id).update_with_window_event(event);
+    }
+    /// Updates the input map of the focused window
+    pub fn update_with_device_event(&mut self, event: &DeviceEvent) {
+        if let Some(input) = self.focused_mut() { input.update_with_device_event(event) }
+    }
+    /// Updates the input map of the focused window, knowing which device the event is from so
+    /// its `device_filter` applies. See `InputMap::update_with_device_event_from`
+    pub fn update_with_device_event_from(&mut self, device_id: DeviceId, event: &DeviceEvent) {
+        if let Some(input) = self.focused_mut() { input.update_with_device_event_from(device_id, event) }
+    }
+    /// Updates the input map of the focused window with gamepad events. The events are thrown away
+    /// if no window is focused so they dont build up
+    #[cfg(feature = "gamepad")]
+    pub fn update_with_gilrs(&mut self, gilrs: &mut gilrs::Gilrs) {
+        match self.focused_mut() {
+            Some(input) => input.update_with_gilrs(gilrs),
+            None => while gilrs.next_event().is_some() {}
+        }
+    }
+    /// Makes every input map ready to recieve new events
+    pub fn init(&mut self) {
+        self.windows.values_mut().for_each(InputMap::init);
+    }
+    /// Returns the input map of a window if its recieved any events
+    pub fn window(&self, id: WindowId) -> Option<&InputMap<F>> {
+        self.windows.get(&id)
+    }
+    pub fn window_mut(&mut self, id: WindowId) -> Option<&mut InputMap<F>> {
+        self.windows.get_mut(&id)
+    }
+    /// Returns the input map of a window, creating it with `make_input` if it doesnt exist yet
+    pub fn window_or_insert(&mut self, id: WindowId) -> &mut InputMap<F> {
+        self.windows.entry(id).or_insert_with(&self.make_input)
+    }
+    /// Removes the input map of a window. Done automatically when its destroyed
+    pub fn remove_window(&mut self, id: WindowId) -> Option<InputMap<F>> {
+        if self.focused == Some(id) { self.focused = None }
+        self.windows.remove(&id)
+    }
+    /// The window with keyboard focus
+    pub fn focused(&self) -> Option<WindowId> {
+        self.focused
+    }
+    /// Returns the input map of the window with keyboard focus
+    pub fn focused_input(&self) -> Option<&InputMap<F>> {
+        self.windows.get(&self.focused?)
+    }
+    pub fn focused_mut(&mut self) -> Option<&mut InputMap<F>> {
+        self.windows.get_mut(&self.focused?)
+    }
+}
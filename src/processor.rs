@@ -0,0 +1,49 @@
+/// A step that changes the value of a bind before it reaches its action. See
+/// `InputMap::set_processors`
+#[derive(Debug, Clone, Copy)]
+pub enum Processor {
+    /// Multiplies the value
+    Scale(f32),
+    /// Flips a 0-1 value so 1 becomes 0, useful for triggers and sliders that are the wrong way
+    /// around. Axis directions are flipped with `InputMap::set_inverted` instead
+    Invert,
+    /// Values below the deadzone become 0 and the rest is rescaled to start from 0
+    Deadzone(f32),
+    /// Keeps the value within a minimum and maximum
+    Clamp(f32, f32),
+    /// Raises the value to a power, values above 1 give more precision near the center
+    Curve(f32),
+    /// Makes the input drive the other axis of a 2D action from `InputMap::set_axis_2d`, so an
+    /// input in `right` moves it up and one in `up` moves it right. Leaves the value alone
+    /// ```
+    /// # use winit_input_map::*;
+    /// # use winit::event::DeviceEvent;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Look }
+    /// let mut input: InputMap<Action> = InputMap::default();
+    /// let mouse_right = InputCode::MOUSE_MOVE_X_POS;
+    /// input.set_axis_2d(Action::Look, Axis2d::new(&[mouse_right], &[], &[], &[]));
+    /// input.set_processors(mouse_right, Action::Look, vec![Processor::Swizzle]);
+    /// input.update_with_device_event(&DeviceEvent::MouseMotion { delta: (10.0, 0.0) });
+    /// let (x, y): (f32, f32) = input.axis_2d(Action::Look).into();
+    /// assert!(x == 0.0 && y > 0.0);
+    /// ```
+    Swizzle,
+    /// Any other function
+    Custom(fn(f32) -> f32)
+}
+impl Processor {
+    pub fn apply(self, val: f32) -> f32 {
+        match self {
+            Self::Scale(scale) => val * scale,
+            Self::Invert => 1.0 - val.clamp(0.0, 1.0),
+            Self::Deadzone(deadzone) if val < deadzone => 0.0,
+            Self::Deadzone(deadzone) if deadzone < 1.0 => (val - deadzone) / (1.0 - deadzone),
+            Self::Deadzone(_) => val,
+            Self::Clamp(min, max) => val.clamp(min, max),
+            Self::Curve(power) => val.max(0.0).powf(power),
+            Self::Swizzle => val,
+            Self::Custom(f) => f(val)
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{InputMap, InputCode, InputSource, GamepadInput, GamepadButton, GamepadAxis, AxisSign};
+use std::{cmp::Eq, hash::Hash};
+/// The brand of a gamepad, used to show the right button prompts. See `InputMap::gamepad_family`
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum GamepadFamily {
+    Xbox,
+    PlayStation,
+    Nintendo,
+    /// Unknown gamepads, which use Xbox names as most gamepads copy its layout
+    #[default]
+    Generic
+}
+impl GamepadFamily {
+    /// Works out the family of a gamepad from its USB vendor id, falling back to its name
+    pub fn of(gamepad: &gilrs::Gamepad) -> Self {
+        match gamepad.vendor_id() {
+            Some(0x045e) => return Self::Xbox,
+            Some(0x054c) => return Self::PlayStation,
+            Some(0x057e) => return Self::Nintendo,
+            _ => ()
+        }
+        let name = gamepad.name().to_lowercase();
+        let named = |names: &[&str]| names.iter().any(|n| name.contains(n));
+        if named(&["xbox"]) { Self::Xbox }
+        else if named(&["playstation", "dualshock", "dualsense", "ps3", "ps4", "ps5"]) { Self::PlayStation }
+        else if named(&["nintendo", "switch", "joy-con", "pro controller"]) { Self::Nintendo }
+        else { Self::Generic }
+    }
+}
+/// A gamepad input as it should be shown to the player, see `InputMap::prompt_for`. Use it to
+/// pick a texture, or `label` for text
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct GamepadPrompt {
+    pub family: GamepadFamily,
+    pub input: GamepadInput
+}
+impl GamepadPrompt {
+    /// The name printed on the button, such as "A" on Xbox and "Cross" on PlayStation for the
+    /// bottom face button
+    pub fn label(&self) -> &'static str {
+        use GamepadFamily::*;
+        use GamepadButton as B;
+        let button = match self.input {
+            GamepadInput::Button(button) => button,
+            GamepadInput::Axis(axis, sign) => return axis_label(axis, sign),
+            GamepadInput::AnyButton => return "Any Button",
+            GamepadInput::Other(_) => return "Extra Button",
+            GamepadInput::Gyro(..) => return "Gyro",
+            GamepadInput::Accel(..) => return "Motion",
+            GamepadInput::TouchpadX(_) | GamepadInput::TouchpadY(_)
+            | GamepadInput::TouchpadTouch => return "Touchpad",
+            GamepadInput::TouchpadClick => return "Touchpad Click"
+        };
+        match (self.family, button) {
+            (PlayStation, B::South) => "Cross",
+            (PlayStation, B::East)  => "Circle",
+            (PlayStation, B::West)  => "Square",
+            (PlayStation, B::North) => "Triangle",
+            (PlayStation, B::LeftTrigger)   => "L1",
+            (PlayStation, B::RightTrigger)  => "R1",
+            (PlayStation, B::LeftTrigger2)  => "L2",
+            (PlayStation, B::RightTrigger2) => "R2",
+            (PlayStation, B::Select) => "Share",
+            (PlayStation, B::Start)  => "Options",
+            (PlayStation, B::Mode)   => "PS",
+            (PlayStation, B::LeftThumb)  => "L3",
+            (PlayStation, B::RightThumb) => "R3",
+            (Nintendo, B::South) => "B",
+            (Nintendo, B::East)  => "A",
+            (Nintendo, B::West)  => "Y",
+            (Nintendo, B::North) => "X",
+            (Nintendo, B::LeftTrigger)   => "L",
+            (Nintendo, B::RightTrigger)  => "R",
+            (Nintendo, B::LeftTrigger2)  => "ZL",
+            (Nintendo, B::RightTrigger2) => "ZR",
+            (Nintendo, B::Select) => "-",
+            (Nintendo, B::Start)  => "+",
+            (Nintendo, B::Mode)   => "Home",
+            (_, B::South) => "A",
+            (_, B::East)  => "B",
+            (_, B::West)  => "X",
+            (_, B::North) => "Y",
+            (_, B::LeftTrigger)   => "LB",
+            (_, B::RightTrigger)  => "RB",
+            (_, B::LeftTrigger2)  => "LT",
+            (_, B::RightTrigger2) => "RT",
+            (_, B::Select) => "View",
+            (_, B::Start)  => "Menu",
+            (_, B::Mode)   => "Guide",
+            (_, B::LeftThumb)  => "LS",
+            (_, B::RightThumb) => "RS",
+            (_, B::DPadUp)    => "Up",
+            (_, B::DPadDown)  => "Down",
+            (_, B::DPadLeft)  => "Left",
+            (_, B::DPadRight) => "Right",
+            (_, B::C) => "C",
+            (_, B::Z) => "Z",
+            (_, B::Unknown) => "?"
+        }
+    }
+}
+fn axis_label(axis: GamepadAxis, sign: AxisSign) -> &'static str {
+    use AxisSign::*;
+    match (axis, sign) {
+        (GamepadAxis::LeftStickX,  Pos) => "Left Stick Right",
+        (GamepadAxis::LeftStickX,  Neg) => "Left Stick Left",
+        (GamepadAxis::LeftStickY,  Pos) => "Left Stick Up",
+        (GamepadAxis::LeftStickY,  Neg) => "Left Stick Down",
+        (GamepadAxis::RightStickX, Pos) => "Right Stick Right",
+        (GamepadAxis::RightStickX, Neg) => "Right Stick Left",
+        (GamepadAxis::RightStickY, Pos) => "Right Stick Up",
+        (GamepadAxis::RightStickY, Neg) => "Right Stick Down",
+        (GamepadAxis::LeftZ, _)  => "Left Trigger",
+        (GamepadAxis::RightZ, _) => "Right Trigger",
+        (GamepadAxis::DPadX, Pos) => "Right",
+        (GamepadAxis::DPadX, Neg) => "Left",
+        (GamepadAxis::DPadY, Pos) => "Up",
+        (GamepadAxis::DPadY, Neg) => "Down",
+        (GamepadAxis::Unknown, _) => "?"
+    }
+}
+impl<F: Hash + Eq + Clone> InputMap<F> {
+    /// Returns the gamepad bind of action as it should be shown for the gamepad last used, or the
+    /// first one connected if none have been used yet. Buttons are preferred over axis.
+    /// ```no_run
+    /// # use winit_input_map::*;
+    /// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+    /// # enum Action { Jump }
+    /// let input = input_map!((Action::Jump, GamepadButton::South)).with_gamepads();
+    /// if let Some(prompt) = input.prompt_for(Action::Jump) {
+    ///     println!("press {} to jump", prompt.label())
+    /// }
+    /// ```
+    pub fn prompt_for(&self, action: F) -> Option<GamepadPrompt> {
+        let family = match self.last_input_source() {
+            Some(InputSource::Gamepad(id)) => self.gamepad_family(id),
+            _ => self.gilrs.as_ref()
+                .and_then(|gilrs| gilrs.gamepads().next())
+                .map_or(GamepadFamily::Generic, |(id, _)| self.gamepad_family(id))
+        };
+        let mut inputs: Vec<GamepadInput> = self.binds.iter()
+            .filter(|(_, actions)| actions.contains(&action))
+            .filter_map(|(code, _)| match code {
+                InputCode::Gamepad { input, .. } => Some(*input),
+                _ => None
+            }).collect();
+        // the binds are in a hash map so sort them to stop the prompt from changing
+        inputs.sort_by_cached_key(|input|
+            (!matches!(input, GamepadInput::Button(_)), format!("{input:?}"))
+        );
+        inputs.first().map(|&input| GamepadPrompt { family, input })
+    }
+}
@@ -0,0 +1,141 @@
+use web_time::Duration;
+/// The sensitivity and feel settings of an `InputMap`, kept together so they can be saved along
+/// with the rest of the players settings. Can be serialized with the `serde` feature
+/// ```
+/// # use winit_input_map::*;
+/// let mut input = input_map!();
+/// input.settings.mouse_scale_y = 0.05;
+/// input.settings.press_sensitivity = 0.3;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct InputSettings {
+    /// How much left and right mouse movement is scaled by. Since most values are from 0-1
+    /// reducing the mouse sensitivity will result in better consistancy
+    pub mouse_scale_x: f32,
+    /// How much up and down mouse movement is scaled by
+    pub mouse_scale_y: f32,
+    /// Smoothing for left and right mouse movement before it reaches actions. `mouse_delta` is
+    /// always unfiltered
+    pub mouse_filter_x: MouseFilter,
+    /// Smoothing for up and down mouse movement before it reaches actions
+    pub mouse_filter_y: MouseFilter,
+    /// How much mouse wheel scrolling, which is in lines, is scaled by. Since most values are
+    /// from 0-1 reducing the scroll sensitivity will result in better consistancy
+    pub scroll_scale: f32,
+    /// How much smooth scrolling from touchpads and some mice, which is in pixels, is scaled by.
+    /// A line is many pixels so this should be much lower than `scroll_scale`
+    pub scroll_pixel_scale: f32,
+    /// How much touchpad pan gestures are scaled by
+    pub pan_scale: f32,
+    /// The minimum value something has to be at to count as being pressed. Values over 1 will
+    /// result in regular buttons being unusable
+    pub press_sensitivity: f32,
+    /// How many pixels of smooth scrolling, such as from a touchpad, make one scroll step or one
+    /// line of `InputMap::scroll_delta_f64`. Mouse wheels make a step for each line. See
+    /// `DeviceInput::ScrollStep`
+    pub scroll_step_pixels: f32,
+    /// How long scrolling takes to slow to half its speed once the wheel stops, so zooming with a
+    /// touchpad feels smooth. The slowing down adds up to as much again as the scroll that started
+    /// it however fast the loops run. Zero, the default, stops it at the end of each loop. See
+    /// `InputMap::scroll_velocity`
+    pub scroll_half_life: Duration,
+    /// How far a 2D axis has to be from the center for `InputMap::axis_angle` to give an angle
+    pub angle_deadzone: f32,
+    /// Scales gyro input codes, which are in degrees per second
+    #[cfg(feature = "gamepad")]
+    pub gyro_scale: f32
+}
+impl Default for InputSettings {
+    fn default() -> Self {
+        Self {
+            mouse_scale_x: 0.1,
+            mouse_scale_y: 0.1,
+            mouse_filter_x: MouseFilter::Off,
+            mouse_filter_y: MouseFilter::Off,
+            scroll_scale: 0.1,
+            scroll_pixel_scale: 0.005,
+            pan_scale: 0.1,
+            press_sensitivity: 0.5,
+            scroll_step_pixels: 50.0,
+            scroll_half_life: Duration::ZERO,
+            angle_deadzone: 0.2,
+            #[cfg(feature = "gamepad")]
+            gyro_scale: 0.01
+        }
+    }
+}
+impl InputSettings {
+    /// Sets the same mouse sensitivity for both directions
+    pub fn set_mouse_scale(&mut self, scale: f32) {
+        self.mouse_scale_x = scale;
+        self.mouse_scale_y = scale;
+    }
+    /// Sets the same mouse smoothing for both directions
+    pub fn set_mouse_filter(&mut self, filter: MouseFilter) {
+        self.mouse_filter_x = filter;
+        self.mouse_filter_y = filter;
+    }
+}
+/// Smoothing for mouse movement, useful for slow cinematic cameras or sculpting where high DPI
+/// mice jitter. See `InputSettings::set_mouse_filter`
+/// ```
+/// # use winit_input_map::*;
+/// let mut input = input_map!();
+/// input.settings.set_mouse_filter(MouseFilter::OneEuro { min_cutoff: 1.0, beta: 0.01 });
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MouseFilter {
+    /// Raw movement
+    #[default]
+    Off,
+    /// Exponential moving average. Smooths the same amount at every speed, `half_life` is in
+    /// seconds and higher values are smoother but lag more
+    Ema { half_life: f32 },
+    /// The 1 euro filter, which smooths slow movements a lot and fast ones barely at all so
+    /// flicks stay responsive. `min_cutoff` is in hertz and lower values smooth slow movement
+    /// more, `beta` is how quickly smoothing drops off as the mouse speeds up in pixels per second.
+    OneEuro { min_cutoff: f32, beta: f32 }
+}
+/// What a `MouseFilter` remembers about an axis between mouse events
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct FilterState {
+    /// How far the smoothed position is behind the real one
+    lag: f32,
+    /// The smoothed speed in pixels per second, used by the 1 euro filter
+    speed: f32
+}
+impl MouseFilter {
+    /// Smooths the movement of one mouse event given the time since the last call. The position
+    /// is smoothed rather than each delta so the result is the same at any polling rate. Its also
+    /// called with no movement every loop so the lag is paid out after the mouse stops
+    pub(crate) fn apply(&self, delta: f32, dt: Duration, state: &mut FilterState) -> f32 {
+        // the mouse was still so the old speed doesnt apply anymore. the lag is kept so no
+        // movement is lost
+        if dt > MAX_DT { state.speed = 0.0 }
+        // events often come in bursts at the same instant so dt has a minimum
+        let dt = dt.clamp(MIN_DT, MAX_DT).as_secs_f32();
+        let alpha = |cutoff: f32| 1.0 / (1.0 + 1.0 / (std::f32::consts::TAU * cutoff * dt));
+        let alpha = match *self {
+            Self::Off => 1.0,
+            Self::Ema { half_life } if half_life <= 0.0 => 1.0,
+            Self::Ema { half_life } => 1.0 - 0.5f32.powf(dt / half_life),
+            Self::OneEuro { min_cutoff, beta } => {
+                state.speed += (delta / dt - state.speed) * alpha(1.0);
+                alpha(min_cutoff + beta * state.speed.abs())
+            }
+        };
+        let behind = state.lag + delta;
+        state.lag = behind * (1.0 - alpha);
+        // whats left is too small to notice so its paid out now rather than trickling forever
+        if state.lag.abs() < 0.01 {
+            state.lag = 0.0;
+            return behind
+        }
+        behind * alpha
+    }
+}
+const MIN_DT: Duration = Duration::from_millis(1);
+const MAX_DT: Duration = Duration::from_millis(50);
@@ -0,0 +1,89 @@
+use crate::{InputMap, InputState};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock};
+use std::{cmp::Eq, hash::Hash};
+/// An input map that can be shared between threads. The main thread feeds it winit events through
+/// `lock` and then calls `publish`, while worker threads such as ECS systems and jobs read the
+/// published frame through an `InputView`. Views only take a read lock long enough to get the
+/// frame, so any number of them can read at once without waiting on the main thread.
+/// ```no_run
+/// # use winit_input_map::*;
+/// # use winit::keyboard::KeyCode;
+/// # #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+/// # enum Action { Jump }
+/// let input = SharedInputMap::new(input_map!((Action::Jump, KeyCode::Space)));
+/// let view = input.view();
+/// // in `about_to_wait`, once the events have been handled
+/// input.publish();
+/// std::thread::spawn(move || if view.pressing(Action::Jump) { /* jump */ });
+/// input.lock().init();
+/// ```
+pub struct SharedInputMap<F: Hash + Eq + Clone> {
+    inner: Arc<Mutex<InputMap<F>>>,
+    frame: Arc<RwLock<Arc<InputState<F>>>>
+}
+impl<F: Hash + Eq + Clone> SharedInputMap<F> {
+    pub fn new(input: InputMap<F>) -> Self {
+        let frame = Arc::new(RwLock::new(Arc::new(input.snapshot())));
+        Self { inner: Arc::new(Mutex::new(input)), frame }
+    }
+    /// Locks the input map to update it or use the rest of its methods
+    pub fn lock(&self) -> MutexGuard<'_, InputMap<F>> {
+        // the input map is still usable if a thread panicked while holding the lock
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+    /// Takes a snapshot of the input map for views to read, usually once a frame after handling
+    /// events and before `init`
+    pub fn publish(&self) {
+        let state = Arc::new(self.lock().snapshot());
+        *self.frame.write().unwrap_or_else(PoisonError::into_inner) = state;
+    }
+    /// A read only view of the input map that can be sent to other threads
+    pub fn view(&self) -> InputView<F> {
+        InputView { inner: self.inner.clone(), frame: self.frame.clone() }
+    }
+}
+impl<F: Hash + Eq + Clone> Clone for SharedInputMap<F> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone(), frame: self.frame.clone() }
+    }
+}
+/// A read only view of a `SharedInputMap`. Queries read the last published frame
+pub struct InputView<F: Hash + Eq + Clone> {
+    inner: Arc<Mutex<InputMap<F>>>,
+    frame: Arc<RwLock<Arc<InputState<F>>>>
+}
+impl<F: Hash + Eq + Clone> InputView<F> {
+    /// Runs `f` with the live input map locked, for queries without a shortcut on the view. This
+    /// waits on the main thread, so prefer the published frame where it will do
+    pub fn read<R>(&self, f: impl FnOnce(&InputMap<F>) -> R) -> R {
+        f(&self.inner.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+    /// The last published frame, see `SharedInputMap::publish`
+    pub fn frame(&self) -> Arc<InputState<F>> {
+        self.frame.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+    pub fn pressing(&self, action: F) -> bool {
+        self.frame().pressing(action)
+    }
+    pub fn pressed(&self, action: F) -> bool {
+        self.frame().pressed(action)
+    }
+    pub fn released(&self, action: F) -> bool {
+        self.frame().released(action)
+    }
+    pub fn action_val(&self, action: F) -> f32 {
+        self.frame().action_val(action)
+    }
+    pub fn axis(&self, pos: F, neg: F) -> f32 {
+        self.frame().axis(pos, neg)
+    }
+    /// A copy of the last published frame
+    pub fn snapshot(&self) -> InputState<F> {
+        (*self.frame()).clone()
+    }
+}
+impl<F: Hash + Eq + Clone> Clone for InputView<F> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone(), frame: self.frame.clone() }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{InputMap, TextEvent};
+use winit::keyboard::KeyCode;
+use std::{cmp::Eq, hash::Hash};
+/// A single line of editable text with a cursor, updated from the text typed into an `InputMap`.
+/// Handles Backspace, Delete, the left and right arrows, Home and End, and Ctrl+Backspace,
+/// Ctrl+Delete and Ctrl+arrows for whole words.
+/// ```
+/// # use winit_input_map::*;
+/// # let mut input = input_map!();
+/// let mut name = TextField::new("player");
+/// // every loop
+/// if name.update(&mut input) { println!("hello {}", name.text) }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct TextField {
+    pub text: String,
+    /// Byte index of the cursor in `text`, always on a char boundary
+    pub cursor: usize
+}
+impl TextField {
+    /// Creates a text field with the cursor at the end
+    pub fn new(text: &str) -> Self {
+        Self { text: text.to_string(), cursor: text.len() }
+    }
+    /// Applies the text typed and editing keys pressed this loop, taking them from
+    /// `InputMap::drain_text`. Returns true if Enter was pressed
+    pub fn update<F: Hash + Eq + Clone>(&mut self, input: &mut InputMap<F>) -> bool {
+        let ctrl = input.modifiers().control_key();
+        let mut submitted = false;
+        for event in input.drain_text() {
+            match event {
+                TextEvent::Text(new) => {
+                    let new: String = new.chars().filter(|c| !c.is_control()).collect();
+                    self.text.insert_str(self.cursor, &new);
+                    self.cursor += new.len();
+                },
+                TextEvent::Key(key) => submitted |= self.edit(key, ctrl)
+            }
+        }
+        submitted
+    }
+    /// applies an editing key, returning true if its enter
+    fn edit(&mut self, key: KeyCode, ctrl: bool) -> bool {
+        self.cursor = self.cursor.min(self.text.len());
+        let (left, right) = match ctrl {
+            true  => (self.word_left(), self.word_right()),
+            false => (self.char_left(), self.char_right())
+        };
+        match key {
+            KeyCode::Backspace => {
+                self.text.replace_range(left..self.cursor, "");
+                self.cursor = left;
+            },
+            KeyCode::Delete => self.text.replace_range(self.cursor..right, ""),
+            KeyCode::ArrowLeft  => self.cursor = left,
+            KeyCode::ArrowRight => self.cursor = right,
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End  => self.cursor = self.text.len(),
+            KeyCode::Enter | KeyCode::NumpadEnter => return true,
+            _ => ()
+        }
+        false
+    }
+    fn char_left(&self) -> usize {
+        self.text[..self.cursor].char_indices().next_back().map_or(0, |(i, _)| i)
+    }
+    fn char_right(&self) -> usize {
+        self.text[self.cursor..].chars().next().map_or(self.cursor, |c| self.cursor + c.len_utf8())
+    }
+    /// the start of the word before the cursor, skipping whitespace first
+    fn word_left(&self) -> usize {
+        let before = self.text[..self.cursor].trim_end();
+        before.char_indices().rev().find(|(_, c)| c.is_whitespace())
+            .map_or(0, |(i, c)| i + c.len_utf8())
+    }
+    /// the end of the word after the cursor, skipping whitespace first
+    fn word_right(&self) -> usize {
+        let after = &self.text[self.cursor..];
+        let start = after.len() - after.trim_start().len();
+        let word = &after[start..];
+        self.cursor + start + word.find(char::is_whitespace).unwrap_or(word.len())
+    }
+}